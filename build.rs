@@ -0,0 +1,31 @@
+//! Compiles the embedded Public Suffix List into a sorted table of rules.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+#[path = "src/psl/dat.rs"]
+mod dat;
+
+/// The vendored Public Suffix List.
+const DAT_FILE: &str = "src/psl/public_suffix_list.dat";
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/psl/dat.rs");
+    println!("cargo::rerun-if-changed={}", DAT_FILE);
+    if env::var_os("CARGO_FEATURE_PSL").is_none() {
+        return;
+    }
+
+    let dat: String = fs::read_to_string(DAT_FILE).expect("the public suffix list is readable");
+    let mut rules: String = String::from("static RULES: &[(&str, u8)] = &[\n");
+    for (suffix, flags) in dat::parse_rules(&dat) {
+        writeln!(rules, "    ({:?}, {}),", suffix, flags).unwrap();
+    }
+    rules.push_str("];\n");
+
+    let out_dir: PathBuf = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets the out dir"));
+    fs::write(out_dir.join("public_suffix_rules.rs"), rules).expect("the rules are writable");
+}
@@ -11,6 +11,9 @@ pub enum Error {
     /// The URL had user info, which is not supported.
     UserInfoNotSupported,
 
+    /// The user info was invalid.
+    InvalidUserInfo,
+
     /// The host was invalid.
     InvalidHost,
 
@@ -29,8 +32,32 @@ pub enum Error {
     /// The fragment was invalid.
     InvalidFragment,
 
-    /// The URL was too long. (must be under 4 GiB)
+    /// The URL was too long. (must be under 4 GiB & within the max length of the parse limits)
     UrlTooLong,
+
+    /// The host was longer than the max host length of the parse limits.
+    HostTooLong,
+
+    /// The path had more segments than the max path segments of the parse limits.
+    TooManyPathSegments,
+
+    /// A path segment was longer than the max segment length of the parse limits.
+    PathSegmentTooLong,
+
+    /// The query had more params than the max params of the parse limits.
+    TooManyParams,
+
+    /// The fragment was longer than the max fragment length of the parse limits.
+    FragmentTooLong,
+
+    /// The percent-encoding was invalid. (a '%' char not followed by two hex digits)
+    InvalidPercentEncoding,
+
+    /// The percent-decoded bytes were not valid UTF-8.
+    InvalidUtf8,
+
+    /// The URL was valid but not normalized, so it could not be borrowed.
+    NotNormalized,
 }
 
 impl Error {
@@ -42,13 +69,22 @@ impl Error {
         match self {
             InvalidScheme => "invalid scheme",
             UserInfoNotSupported => "user info is not supported",
+            InvalidUserInfo => "invalid user info",
             InvalidHost => "invalid host",
             InvalidPort => "invalid port",
             InvalidPath => "invalid path",
             InvalidQuery => "invalid query",
             InvalidParam => "invalid query parameter",
             InvalidFragment => "invalid fragment",
-            UrlTooLong => "URL too long (>= 4 GiB)",
+            UrlTooLong => "URL too long",
+            HostTooLong => "host too long",
+            TooManyPathSegments => "too many path segments",
+            PathSegmentTooLong => "path segment too long",
+            TooManyParams => "too many query parameters",
+            FragmentTooLong => "fragment too long",
+            InvalidPercentEncoding => "invalid percent-encoding",
+            InvalidUtf8 => "invalid UTF-8",
+            NotNormalized => "URL not normalized",
         }
     }
 }
@@ -1,12 +1,14 @@
-use crate::Error;
-use std::fmt::{Display, Formatter};
+use crate::{Error, ParseError};
+use std::fmt::{Display, Formatter, Write};
 
 /// An error parsing a web-based URL from an owned string.
 ///
-/// The invalid URL string can be recovered, like `std::string::FromUtf8Error`.
+/// The invalid URL string can be recovered, like `std::string::FromUtf8Error`. The error is displayed on one line like
+/// the parse error. The alternate form (`{:#}`) adds the `snippet`, the URL with the invalid part underlined, on the
+/// lines below it.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub struct InvalidUrlError {
-    error: Error,
+    error: ParseError,
     url: String,
 }
 
@@ -14,7 +16,7 @@ impl InvalidUrlError {
     //! Construction
 
     /// Creates a new invalid URL error.
-    pub(crate) const fn new(error: Error, url: String) -> Self {
+    pub(crate) const fn new(error: ParseError, url: String) -> Self {
         Self { error, url }
     }
 }
@@ -22,9 +24,15 @@ impl InvalidUrlError {
 impl InvalidUrlError {
     //! Properties
 
-    /// Gets the parse error.
+    /// Gets the error.
     #[must_use]
     pub const fn error(&self) -> Error {
+        self.error.error()
+    }
+
+    /// Gets the detailed parse error.
+    #[must_use]
+    pub const fn parse_error(&self) -> ParseError {
         self.error
     }
 
@@ -46,17 +54,141 @@ impl InvalidUrlError {
 }
 
 impl From<InvalidUrlError> for Error {
+    fn from(error: InvalidUrlError) -> Self {
+        error.error.error()
+    }
+}
+
+impl From<InvalidUrlError> for ParseError {
     fn from(error: InvalidUrlError) -> Self {
         error.error
     }
 }
 
+impl InvalidUrlError {
+    //! Snippet
+
+    /// The max number of chars of context shown on each side of the span.
+    const CONTEXT_CHARS: usize = 32;
+
+    /// The max number of chars of the span that are underlined.
+    const SPAN_CHARS: usize = 64;
+
+    /// Gets the snippet of the URL around the span with the span underlined by carets.
+    ///
+    /// The snippet is two lines: the URL & the carets. A long URL is cut to the context around the span & the cuts are
+    /// marked with "...". The control chars are shown as '\u{FFFD}' so the carets stay aligned.
+    #[must_use]
+    pub fn snippet(&self) -> String {
+        let mut snippet: String = String::new();
+        self.write_snippet(&mut snippet)
+            .expect("writing to a string does not fail");
+        snippet
+    }
+
+    /// Writes the snippet of the URL around the span with the span underlined by carets. (see `snippet`)
+    fn write_snippet<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: Write,
+    {
+        let url: &str = self.url.as_str();
+        let (start, end) = (self.error.start(), self.error.end());
+
+        let window_start: usize = url[..start]
+            .char_indices()
+            .rev()
+            .nth(Self::CONTEXT_CHARS - 1)
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        let span_end: usize = url[start..end]
+            .char_indices()
+            .nth(Self::SPAN_CHARS)
+            .map(|(index, _)| start + index)
+            .unwrap_or(end);
+        let window_end: usize = url[span_end..]
+            .char_indices()
+            .nth(Self::CONTEXT_CHARS)
+            .map(|(index, _)| span_end + index)
+            .unwrap_or(url.len());
+
+        let prefix: &str = if window_start == 0 { "" } else { "..." };
+        let suffix: &str = if window_end == url.len() { "" } else { "..." };
+        f.write_str(prefix)?;
+        for c in url[window_start..window_end].chars() {
+            write!(f, "{}", if c.is_control() { '\u{FFFD}' } else { c })?;
+        }
+        writeln!(f, "{}", suffix)?;
+
+        let indent: usize = prefix.len() + url[window_start..start].chars().count();
+        let carets: usize = url[start..span_end].chars().count().max(1);
+        write!(f, "{}{}", " ".repeat(indent), "^".repeat(carets))
+    }
+}
+
 impl Display for InvalidUrlError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.pad(self.error.message())
+        if f.alternate() {
+            writeln!(f, "{}", self.error)?;
+            self.write_snippet(f)
+        } else {
+            Display::fmt(&self.error, f)
+        }
     }
 }
 
-/// The `source` is not the parse error: its message is this error's message, so a chain would just print it twice. Use
-/// `error` for the typed parse error.
+/// The `source` is not the parse error: its message is this error's message, so a chain would just print it twice.
+/// Use `parse_error` for the detailed parse error.
 impl std::error::Error for InvalidUrlError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InvalidUrlError, ParseErrorReason, WebUrl};
+
+    #[test]
+    fn display() {
+        let test_cases: &[(&str, &str, &str)] = &[
+            (
+                "http://ho st/",
+                "invalid host: invalid char ' ' at bytes 9..10",
+                "http://ho st/\n         ^",
+            ),
+            ("http:///p", "invalid host: empty host at byte 7", "http:///p\n       ^"),
+            (
+                "http://host:99999/",
+                "invalid port: port too large (> 65535) at bytes 12..17",
+                "http://host:99999/\n            ^^^^^",
+            ),
+            (
+                "http://host/\u{4f60}",
+                "invalid path: invalid char '\u{4f60}' at bytes 12..15",
+                "http://host/\u{4f60}\n            ^",
+            ),
+            (
+                "http://host/\n",
+                "invalid path: invalid char '\\n' at bytes 12..13",
+                "http://host/\u{FFFD}\n            ^",
+            ),
+        ];
+        for (input, display, snippet) in test_cases {
+            let error: InvalidUrlError = WebUrl::try_from(input.to_string()).unwrap_err();
+            assert_eq!(error.to_string(), *display, "input={}", input);
+            assert_eq!(error.snippet(), *snippet, "input={}", input);
+            assert_eq!(
+                format!("{:#}", error),
+                format!("{}\n{}", display, snippet),
+                "input={}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn display_long_url() {
+        let input: String = format!("http://host/{}%zz{}", "a".repeat(100), "b".repeat(100));
+        let error: InvalidUrlError = WebUrl::try_from(input).unwrap_err();
+        assert_eq!(error.parse_error().reason(), ParseErrorReason::InvalidPercentEscape);
+
+        let expected: String = format!("...{}%zz{}...\n{}^^^", "a".repeat(32), "b".repeat(32), " ".repeat(35));
+        assert_eq!(error.snippet(), expected);
+    }
+}
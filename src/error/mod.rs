@@ -1,5 +1,13 @@
 pub use error::*;
 pub use invalid_url_error::*;
+pub use parse_error::*;
+pub use parse_error_reason::*;
+#[cfg(feature = "serde")]
+pub use query_serde_error::*;
 
 mod error;
 mod invalid_url_error;
+mod parse_error;
+mod parse_error_reason;
+#[cfg(feature = "serde")]
+mod query_serde_error;
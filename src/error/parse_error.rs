@@ -0,0 +1,101 @@
+use crate::{Error, ParseErrorReason};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// A detailed error parsing a web-based URL.
+///
+/// The error has the byte span of the invalid part of the URL, the invalid char when there is one, & the reason the
+/// part was invalid. The span is a range of byte offsets into the URL string on char boundaries. It is empty when the
+/// invalid part is missing, as for an empty host, & then marks where the part should be.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ParseError {
+    error: Error,
+    reason: ParseErrorReason,
+    start: usize,
+    end: usize,
+    invalid_char: Option<char>,
+}
+
+impl ParseError {
+    //! Construction
+
+    /// Creates a new parse error.
+    pub(crate) const fn new(
+        error: Error,
+        reason: ParseErrorReason,
+        span: (usize, usize),
+        invalid_char: Option<char>,
+    ) -> Self {
+        debug_assert!(span.0 <= span.1);
+
+        Self {
+            error,
+            reason,
+            start: span.0,
+            end: span.1,
+            invalid_char,
+        }
+    }
+}
+
+impl ParseError {
+    //! Properties
+
+    /// Gets the error.
+    #[must_use]
+    pub const fn error(self) -> Error {
+        self.error
+    }
+
+    /// Gets the reason.
+    #[must_use]
+    pub const fn reason(self) -> ParseErrorReason {
+        self.reason
+    }
+
+    /// Gets the start byte offset of the span.
+    #[must_use]
+    pub const fn start(self) -> usize {
+        self.start
+    }
+
+    /// Gets the end byte offset of the span. (exclusive)
+    #[must_use]
+    pub const fn end(self) -> usize {
+        self.end
+    }
+
+    /// Gets the span of the invalid part of the URL.
+    #[must_use]
+    pub const fn span(self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Gets the optional invalid char.
+    #[must_use]
+    pub const fn invalid_char(self) -> Option<char> {
+        self.invalid_char
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        error.error
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.error, self.reason)?;
+        if let Some(c) = self.invalid_char {
+            write!(f, " '{}'", c.escape_debug())?;
+        }
+        if self.start == self.end {
+            write!(f, " at byte {}", self.start)
+        } else {
+            write!(f, " at bytes {}..{}", self.start, self.end)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,95 @@
+use crate::ParseErrorReason::*;
+use std::fmt::{Display, Formatter};
+
+/// The reason a web-based URL could not be parsed. (see [`ParseError`](crate::ParseError))
+///
+/// The reason is finer grained than the [`Error`](crate::Error), which only names the part of the URL that was invalid.
+#[non_exhaustive]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ParseErrorReason {
+    /// The URL had no ':' to end the scheme.
+    MissingScheme,
+
+    /// The scheme was empty.
+    EmptyScheme,
+
+    /// The scheme was not followed by the "//" that starts the authority.
+    MissingAuthority,
+
+    /// The URL had user info, which was not accepted.
+    UserInfo,
+
+    /// The host was empty.
+    EmptyHost,
+
+    /// The bracketed IPv6 address was invalid.
+    InvalidIPv6Address,
+
+    /// A domain label was empty.
+    EmptyLabel,
+
+    /// A domain label was too long. (over 63 bytes)
+    LabelTooLong,
+
+    /// A domain label started or ended with a '-'.
+    LabelHyphen,
+
+    /// The domain was too long. (over 253 bytes)
+    DomainTooLong,
+
+    /// The Unicode domain could not be converted to its A-label form, or an `xn--` label is not a valid A-label.
+    /// (see UTS #46)
+    InvalidInternationalDomain,
+
+    /// The port was too large. (over 65535)
+    PortOverflow,
+
+    /// A char was not valid in its part of the URL.
+    InvalidChar,
+
+    /// A '%' char was not followed by two hex digits.
+    InvalidPercentEscape,
+
+    /// The URL was too long. (must be under 4 GiB)
+    UrlTooLong,
+
+    /// A limit of the [`ParseLimits`](crate::ParseLimits) was exceeded. The error names the limit.
+    LimitExceeded,
+
+    /// The invalid part could not be found. The span is the whole URL.
+    Unknown,
+}
+
+impl ParseErrorReason {
+    //! Display
+
+    /// Gets the reason message.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            MissingScheme => "missing scheme",
+            EmptyScheme => "empty scheme",
+            MissingAuthority => "missing '//' after the scheme",
+            UserInfo => "user info is not accepted",
+            EmptyHost => "empty host",
+            InvalidIPv6Address => "invalid IPv6 address",
+            EmptyLabel => "empty domain label",
+            LabelTooLong => "domain label too long (> 63 bytes)",
+            LabelHyphen => "domain label starts or ends with '-'",
+            DomainTooLong => "domain too long (> 253 bytes)",
+            InvalidInternationalDomain => "invalid international domain name",
+            PortOverflow => "port too large (> 65535)",
+            InvalidChar => "invalid char",
+            InvalidPercentEscape => "invalid percent escape",
+            UrlTooLong => "URL too long (>= 4 GiB)",
+            LimitExceeded => "parse limit exceeded",
+            Unknown => "unknown reason",
+        }
+    }
+}
+
+impl Display for ParseErrorReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.message())
+    }
+}
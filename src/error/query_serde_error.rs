@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+
+/// An error deserializing a typed value from a query or serializing one into a query.
+///
+/// The error has the message of the serde error, like ``missing field `id` `` or `invalid digit found in string`.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct QuerySerdeError {
+    message: String,
+}
+
+impl QuerySerdeError {
+    //! Construction
+
+    /// Creates a new query serde error from the serde `error`.
+    pub(crate) fn new<E>(error: E) -> Self
+    where
+        E: Display,
+    {
+        Self {
+            message: error.to_string(),
+        }
+    }
+}
+
+impl QuerySerdeError {
+    //! Properties
+
+    /// Gets the error message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl Display for QuerySerdeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.message())
+    }
+}
+
+impl std::error::Error for QuerySerdeError {}
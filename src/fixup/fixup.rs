@@ -0,0 +1,41 @@
+use crate::Fixup::*;
+use std::fmt::{Display, Formatter};
+
+/// A fix applied to a user-typed URL. (see [`WebUrl::parse_fixup`](crate::WebUrl::parse_fixup))
+#[non_exhaustive]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Fixup {
+    /// The leading & trailing whitespace was trimmed. (` example.com ` -> `example.com`)
+    TrimmedWhitespace,
+
+    /// The URL had no scheme, so the default scheme was added. (`example.com` -> `https://example.com`)
+    AddedScheme,
+
+    /// The slashes after a scheme with a default port were missing or malformed & were replaced with "//".
+    /// (`http:example.com` -> `http://example.com`)
+    FixedSlashes,
+
+    /// The chars the path, query, or fragment does not accept were percent-encoded. (`/a b` -> `/a%20b`)
+    EncodedChars,
+}
+
+impl Fixup {
+    //! Display
+
+    /// Gets the fixup message.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            TrimmedWhitespace => "trimmed the whitespace",
+            AddedScheme => "added the default scheme",
+            FixedSlashes => "fixed the slashes after the scheme",
+            EncodedChars => "percent-encoded the invalid chars",
+        }
+    }
+}
+
+impl Display for Fixup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.message())
+    }
+}
@@ -0,0 +1,4 @@
+pub use fixup::*;
+
+mod fixup;
+mod parse;
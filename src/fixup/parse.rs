@@ -0,0 +1,84 @@
+use crate::parse::{fix_up, parse_str};
+use crate::{Error, Fixup, Normalizer, Scheme, WebUrl};
+
+impl WebUrl {
+    //! Fixup Parsing
+
+    /// Parses the user-typed URL `s` the way a browser address bar does & gets the fixups that were applied.
+    ///
+    /// The URL is fixed before it is parsed: the whitespace around it is trimmed, the `default_scheme` is added when it
+    /// has no scheme (`example.com/path` or `localhost:3000`), the slashes after a scheme with a default port are fixed
+    /// (`http:example.com`), & the chars the path, query, or fragment does not accept are percent-encoded (`?q=a b`).
+    /// The fixed URL is then parsed & normalized with [`Normalizer::DEFAULT`]. The fixups are in the order they were
+    /// applied & are empty when the URL was already valid.
+    ///
+    /// # Errors
+    /// The fixed URL is validated like a parsed URL, so a URL that cannot be fixed fails with the error for its invalid
+    /// component. The host & port are never fixed. A URL with a scheme that has no authority, like `mailto:user` or
+    /// `javascript:alert(1)`, is an `InvalidScheme`.
+    pub fn parse_fixup(s: &str, default_scheme: Scheme) -> Result<(Self, Vec<Fixup>), Error> {
+        let (url, fixups) = fix_up(s, default_scheme);
+        let url: WebUrl = parse_str(&url, Normalizer::DEFAULT)?;
+        Ok((url, fixups))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::{InvalidHost, InvalidPort, InvalidScheme};
+    use crate::Fixup::*;
+    use crate::{Error, Fixup, Scheme, WebUrl};
+
+    #[test]
+    fn parse_fixup() {
+        type Expected = Result<(&'static str, &'static [Fixup]), Error>;
+        let test_cases: &[(&str, Expected)] = &[
+            ("https://example.com/", Ok(("https://example.com/", &[]))),
+            ("example.com/path", Ok(("https://example.com/path", &[AddedScheme]))),
+            ("localhost:3000", Ok(("https://localhost:3000/", &[AddedScheme]))),
+            (
+                " HTTPS://Example.com ",
+                Ok(("https://example.com/", &[TrimmedWhitespace])),
+            ),
+            (
+                "www.example.com?q=a b",
+                Ok(("https://www.example.com/?q=a%20b", &[AddedScheme, EncodedChars])),
+            ),
+            (
+                "\thttp:\\\\example.com/a|b#c#d\n",
+                Ok((
+                    "http://example.com/a%7Cb#c%23d",
+                    &[TrimmedWhitespace, FixedSlashes, EncodedChars],
+                )),
+            ),
+            ("//example.com/", Ok(("https://example.com/", &[AddedScheme]))),
+            ("http:///example.com", Ok(("http://example.com/", &[FixedSlashes]))),
+            ("ftp://files.example.com", Ok(("ftp://files.example.com/", &[]))),
+            ("git+ssh://host/repo", Ok(("git+ssh://host/repo", &[]))),
+            (
+                "example.com/100%",
+                Ok(("https://example.com/100%25", &[AddedScheme, EncodedChars])),
+            ),
+            ("example.com/%7e", Ok(("https://example.com/~", &[AddedScheme]))),
+            // The host & port are never fixed.
+            ("exa mple.com", Err(InvalidHost)),
+            ("localhost:99999", Err(InvalidPort)),
+            ("   ", Err(InvalidHost)),
+            // A scheme with no authority is not a web-based URL.
+            ("mailto:user", Err(InvalidScheme)),
+            ("mailto:user@example.com", Err(InvalidScheme)),
+            ("tel:+1-555-0100", Err(InvalidScheme)),
+            (" javascript:alert(1)", Err(InvalidScheme)),
+            ("JavaScript:void(0)", Err(InvalidScheme)),
+            ("data:text/plain,hi", Err(InvalidScheme)),
+        ];
+        for (input, expected) in test_cases {
+            let result: Result<(WebUrl, Vec<Fixup>), Error> = WebUrl::parse_fixup(input, Scheme::HTTPS);
+            let result: Result<(&str, &[Fixup]), Error> = result
+                .as_ref()
+                .map(|(url, fixups)| (url.as_str(), fixups.as_slice()))
+                .map_err(|error| *error);
+            assert_eq!(result, *expected, "input={:?}", input);
+        }
+    }
+}
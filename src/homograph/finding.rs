@@ -0,0 +1,33 @@
+use std::fmt::{Display, Formatter};
+
+/// A finding of a [`HostAnalyzer`](crate::homograph::HostAnalyzer).
+///
+/// The labels are indexed from the left of the host, so the `example` label of `www.example.com` is label 1.
+#[non_exhaustive]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Finding {
+    /// The label mixes scripts that are not commonly mixed, like Latin & Cyrillic.
+    ///
+    /// The Han, Hiragana, Katakana, Hangul, & Bopomofo mixes that UTS #39 allows as highly restrictive are not mixed.
+    MixedScript { label: usize },
+
+    /// The label has an invisible char. (`Default_Ignorable`, like a zero-width joiner)
+    InvisibleChar { label: usize, c: char },
+
+    /// The label has a char that is restricted from identifiers, like an obsolete or technical char.
+    RestrictedChar { label: usize, c: char },
+
+    /// The host, or its ending labels, is confusable with the brand domain but is not the brand domain.
+    Confusable { brand: String },
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MixedScript { label } => write!(f, "mixed scripts in label {}", label),
+            Self::InvisibleChar { label, c } => write!(f, "invisible char U+{:04X} in label {}", *c as u32, label),
+            Self::RestrictedChar { label, c } => write!(f, "restricted char U+{:04X} in label {}", *c as u32, label),
+            Self::Confusable { brand } => write!(f, "confusable with {}", brand),
+        }
+    }
+}
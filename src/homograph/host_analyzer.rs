@@ -0,0 +1,211 @@
+use crate::homograph::{Finding, HostReport, RestrictionLevel};
+use address::{DomainRef, HostRef};
+use unicode_security::general_security_profile::IdentifierType;
+use unicode_security::{GeneralSecurityProfile, MixedScript, RestrictionLevelDetection, skeleton};
+
+/// Analyzes hosts for homographs & confusables with [UTS #39](https://www.unicode.org/reports/tr39/).
+///
+/// The brand domains are the domains a host must not be confusable with, like `paypal.com`. Two domains are confusable
+/// when their skeletons are equal, so `pаypal.com` (Cyrillic 'а') & `paypa1.com` are both confusable with
+/// `paypal.com`.
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use web_url::WebUrl;
+/// use web_url::homograph::{Finding, HostAnalyzer, HostReport, Risk};
+///
+/// let analyzer: HostAnalyzer = HostAnalyzer::default().with_brand("paypal.com");
+/// let url: WebUrl = WebUrl::from_str("https://p\u{430}ypal.com/login").unwrap();
+/// let report: HostReport = analyzer.analyze(url.host());
+///
+/// assert_eq!(report.host(), "p\u{430}ypal.com");
+/// assert_eq!(report.risk(), Risk::High);
+/// assert!(report.findings().contains(&Finding::MixedScript { label: 0 }));
+/// assert!(report.findings().contains(&Finding::Confusable { brand: "paypal.com".to_string() }));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct HostAnalyzer {
+    brands: Vec<(String, String)>,
+}
+
+impl HostAnalyzer {
+    //! Builders
+
+    /// Adds the `brand` domain. (`paypal.com`)
+    #[must_use]
+    pub fn with_brand(mut self, brand: &str) -> Self {
+        let brand: String = brand.to_lowercase();
+        let brand_skeleton: String = skeleton(&brand).collect();
+        self.brands.push((brand, brand_skeleton));
+        self
+    }
+}
+
+impl HostAnalyzer {
+    //! Analysis
+
+    /// Analyzes the `host`.
+    ///
+    /// The IDN labels of a domain are decoded to Unicode first. (a label that is not valid Punycode is left as it is)
+    /// An IP address has no findings.
+    #[must_use]
+    pub fn analyze(&self, host: HostRef) -> HostReport {
+        let domain: DomainRef = match host {
+            HostRef::Address(ip) => {
+                return HostReport {
+                    host: ip.to_string(),
+                    restriction_level: RestrictionLevel::AsciiOnly,
+                    findings: Vec::new(),
+                };
+            }
+            HostRef::Name(domain) => domain,
+        };
+        let host: String = domain.to_unicode().unwrap_or_else(|_| domain.name().to_string());
+
+        let mut restriction_level: RestrictionLevel = RestrictionLevel::AsciiOnly;
+        let mut findings: Vec<Finding> = Vec::new();
+        for (label_index, label) in host.split('.').enumerate() {
+            let level: RestrictionLevel = label.detect_restriction_level().into();
+            restriction_level = restriction_level.max(level);
+            if level > RestrictionLevel::HighlyRestrictive && !label.is_single_script() {
+                findings.push(Finding::MixedScript { label: label_index });
+            }
+            for c in label.chars() {
+                if c.identifier_type() == Some(IdentifierType::Default_Ignorable) {
+                    findings.push(Finding::InvisibleChar { label: label_index, c });
+                } else if !c.identifier_allowed() {
+                    findings.push(Finding::RestrictedChar { label: label_index, c });
+                }
+            }
+        }
+        for (brand, brand_skeleton) in &self.brands {
+            if let Some(ending) = Self::ending_labels(&host, brand.split('.').count())
+                && ending != brand
+                && skeleton(ending).eq(brand_skeleton.chars())
+            {
+                findings.push(Finding::Confusable { brand: brand.clone() });
+            }
+        }
+
+        HostReport {
+            host,
+            restriction_level,
+            findings,
+        }
+    }
+
+    /// Gets the ending `count` labels of the `host`. (`None` when the host has fewer labels)
+    fn ending_labels(host: &str, count: usize) -> Option<&str> {
+        match host.rmatch_indices('.').nth(count - 1) {
+            Some((dot, _)) => Some(&host[dot + 1..]),
+            None => (host.split('.').count() == count).then_some(host),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WebUrl;
+    use crate::homograph::Finding::*;
+    use crate::homograph::RestrictionLevel::*;
+    use crate::homograph::{Finding, HostAnalyzer, HostReport, RestrictionLevel, Risk};
+    use std::str::FromStr;
+
+    #[test]
+    fn analyze() -> Result<(), Box<dyn std::error::Error>> {
+        let analyzer: HostAnalyzer = HostAnalyzer::default().with_brand("paypal.com").with_brand("Scope.com");
+        let paypal = || Confusable {
+            brand: "paypal.com".to_string(),
+        };
+        let test_cases: &[(&str, RestrictionLevel, Vec<Finding>)] = &[
+            ("https://paypal.com/", AsciiOnly, vec![]),
+            ("https://www.paypal.com/", AsciiOnly, vec![]),
+            ("https://paypal.com.example.com/", AsciiOnly, vec![]),
+            ("https://127.0.0.1/", AsciiOnly, vec![]),
+            ("https://paypa1.com/", AsciiOnly, vec![paypal()]),
+            (
+                "https://p\u{430}ypal.com/",
+                MinimallyRestrictive,
+                vec![MixedScript { label: 0 }, paypal()],
+            ),
+            (
+                "https://xn--pypal-4ve.com/",
+                MinimallyRestrictive,
+                vec![MixedScript { label: 0 }, paypal()],
+            ),
+            (
+                "https://login.p\u{430}ypal.com/",
+                MinimallyRestrictive,
+                vec![MixedScript { label: 1 }, paypal()],
+            ),
+            (
+                "https://\u{455}\u{441}\u{43e}\u{440}\u{435}.com/",
+                SingleScript,
+                vec![Confusable {
+                    brand: "scope.com".to_string(),
+                }],
+            ),
+            ("https://b\u{fc}cher.example/", SingleScript, vec![]),
+            (
+                "https://\u{4f8b}\u{3048}.\u{30c6}\u{30b9}\u{30c8}/",
+                SingleScript,
+                vec![],
+            ),
+            (
+                "https://\u{3b1}\u{3b2}c.example/",
+                MinimallyRestrictive,
+                vec![MixedScript { label: 0 }],
+            ),
+            (
+                "https://\u{915}\u{94d}\u{200c}\u{937}.example/",
+                Unrestricted,
+                vec![InvisibleChar {
+                    label: 0,
+                    c: '\u{200c}',
+                }],
+            ),
+            (
+                "https://a\u{2d0}b.example/",
+                Unrestricted,
+                vec![RestrictedChar { label: 0, c: '\u{2d0}' }],
+            ),
+        ];
+        for (input, level, findings) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            let report: HostReport = analyzer.analyze(url.host());
+            assert_eq!(report.restriction_level(), *level, "input={}", input);
+            assert_eq!(report.findings(), findings.as_slice(), "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn risk() -> Result<(), Box<dyn std::error::Error>> {
+        let analyzer: HostAnalyzer = HostAnalyzer::default().with_brand("paypal.com");
+        let test_cases: &[(&str, Risk)] = &[
+            ("https://example.com/", Risk::None),
+            ("https://a\u{2d0}b.example/", Risk::Low),
+            ("https://\u{915}\u{94d}\u{200c}\u{937}.example/", Risk::High),
+            ("https://paypa1.com/", Risk::High),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(analyzer.analyze(url.host()).risk(), *expected, "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn finding_display() {
+        assert_eq!(MixedScript { label: 1 }.to_string(), "mixed scripts in label 1");
+        assert_eq!(
+            InvisibleChar {
+                label: 0,
+                c: '\u{200c}'
+            }
+            .to_string(),
+            "invisible char U+200C in label 0"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::homograph::{Finding, RestrictionLevel, Risk};
+
+/// The report of a [`HostAnalyzer`](crate::homograph::HostAnalyzer).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HostReport {
+    pub(in crate::homograph) host: String,
+    pub(in crate::homograph) restriction_level: RestrictionLevel,
+    pub(in crate::homograph) findings: Vec<Finding>,
+}
+
+impl HostReport {
+    //! Properties
+
+    /// Gets the host that was analyzed, with its IDN labels decoded to Unicode.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Gets the restriction level of the host, which is the least restrictive level of its labels.
+    #[must_use]
+    pub const fn restriction_level(&self) -> RestrictionLevel {
+        self.restriction_level
+    }
+
+    /// Gets the findings.
+    #[must_use]
+    pub fn findings(&self) -> &[Finding] {
+        &self.findings
+    }
+
+    /// Gets the risk of the findings.
+    #[must_use]
+    pub fn risk(&self) -> Risk {
+        self.findings
+            .iter()
+            .map(|finding| match finding {
+                Finding::RestrictedChar { .. } => Risk::Low,
+                _ => Risk::High,
+            })
+            .max()
+            .unwrap_or(Risk::None)
+    }
+}
@@ -0,0 +1,19 @@
+//! Homograph & confusable host detection with [UTS #39](https://www.unicode.org/reports/tr39/).
+//!
+//! A [`HostAnalyzer`] decodes the IDN labels of a host & reports the labels that mix scripts, the invisible &
+//! restricted chars, & the hosts that are confusable with a supplied brand domain, like `pаypal.com` with a Cyrillic
+//! 'а'.
+//!
+//! The report is a heuristic for flagging links to a user. A host without findings is not known to be safe.
+
+pub use finding::*;
+pub use host_analyzer::*;
+pub use host_report::*;
+pub use restriction_level::*;
+pub use risk::*;
+
+mod finding;
+mod host_analyzer;
+mod host_report;
+mod restriction_level;
+mod risk;
@@ -0,0 +1,38 @@
+/// The restriction level of a host, from the most to the least restrictive.
+///
+/// # UTS #39
+/// <https://www.unicode.org/reports/tr39/#Restriction_Level_Detection>
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum RestrictionLevel {
+    /// Every char is ASCII.
+    AsciiOnly,
+
+    /// Every label is in a single script.
+    SingleScript,
+
+    /// A label mixes Latin with Han & Hiragana & Katakana, Han & Bopomofo, or Han & Hangul.
+    HighlyRestrictive,
+
+    /// A label mixes Latin with one other recommended script, except Cyrillic & Greek.
+    ModeratelyRestrictive,
+
+    /// A label mixes any of the allowed chars.
+    MinimallyRestrictive,
+
+    /// A label has a restricted char.
+    Unrestricted,
+}
+
+impl From<unicode_security::RestrictionLevel> for RestrictionLevel {
+    fn from(level: unicode_security::RestrictionLevel) -> Self {
+        use unicode_security::RestrictionLevel as Level;
+        match level {
+            Level::ASCIIOnly => Self::AsciiOnly,
+            Level::SingleScript => Self::SingleScript,
+            Level::HighlyRestrictive => Self::HighlyRestrictive,
+            Level::ModeratelyRestrictive => Self::ModeratelyRestrictive,
+            Level::MinimallyRestrictive => Self::MinimallyRestrictive,
+            Level::Unrestricted => Self::Unrestricted,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// The risk of a [`HostReport`](crate::homograph::HostReport).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Risk {
+    /// The host has no findings.
+    None,
+
+    /// The host has only restricted chars, which are unusual but not deceptive by themselves.
+    Low,
+
+    /// The host mixes scripts, has invisible chars, or is confusable with a brand domain.
+    High,
+}
@@ -5,11 +5,32 @@
 pub use address;
 
 pub use error::*;
+pub use fixup::*;
+pub use normalizer::*;
+pub use origin::*;
 pub use parts::*;
+#[cfg(feature = "psl")]
+pub use psl::*;
+pub use redact::*;
 pub use web_url::*;
 
 mod error;
+mod fixup;
+mod normalizer;
+mod origin;
 mod parts;
+#[cfg(feature = "psl")]
+mod psl;
+mod redact;
 mod web_url;
 
+#[cfg(feature = "homograph")]
+pub mod homograph;
+pub mod lint;
+pub mod percent;
+pub mod redirect;
+pub mod ssrf;
+
 mod parse;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,52 @@
+use crate::lint::LintKind::*;
+use std::fmt::{Display, Formatter};
+
+/// The kind of a [`QueryLint`](crate::lint::QueryLint).
+#[non_exhaustive]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum LintKind {
+    /// The name is the same as an earlier name. Frameworks take the first, the last, or all of the values.
+    DuplicateName,
+
+    /// The name differs from an earlier name only by case or encoding. (`a+b` & `A%20B`)
+    AmbiguousName,
+
+    /// The param has an encoded '&' or '=' char, which is a separator once a framework decodes the query twice.
+    EncodedSeparator,
+
+    /// The param has a ';' char, which some frameworks split params on.
+    Semicolon,
+
+    /// The param has a double-encoded escape, which decodes to another escape. (`%252e` -> `%2e`)
+    DoubleEncoding,
+
+    /// The param has a value but an empty name. (`=value`)
+    EmptyName,
+
+    /// The value is longer than the limit of the [`QueryLinter`](crate::lint::QueryLinter).
+    OverlongValue,
+}
+
+impl LintKind {
+    //! Display
+
+    /// Gets the lint message.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            DuplicateName => "duplicate name",
+            AmbiguousName => "name differs from an earlier name only by case or encoding",
+            EncodedSeparator => "encoded separator",
+            Semicolon => "semicolon separator",
+            DoubleEncoding => "double-encoded escape",
+            EmptyName => "empty name",
+            OverlongValue => "overlong value",
+        }
+    }
+}
+
+impl Display for LintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.message())
+    }
+}
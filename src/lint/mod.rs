@@ -0,0 +1,13 @@
+//! Linting for the query params that back-end frameworks parse differently, which attackers exploit for parameter
+//! pollution.
+//!
+//! A [`QueryLinter`] reports the duplicate & ambiguous names, the hidden separators, the double-encoded escapes, the
+//! empty names, & the overlong values of a [`Query`](crate::Query), each with the byte range of the query it refers to.
+
+pub use lint_kind::*;
+pub use query_lint::*;
+pub use query_linter::*;
+
+mod lint_kind;
+mod query_lint;
+mod query_linter;
@@ -0,0 +1,44 @@
+use crate::lint::LintKind;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// A lint of a query param.
+///
+/// The range is the byte range of the query string it refers to, including the leading '?'. So the range of the `b`
+/// name of the query `?a=1&b=2` is `5..6`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct QueryLint {
+    kind: LintKind,
+    range: Range<usize>,
+}
+
+impl QueryLint {
+    //! Construction
+
+    /// Creates a new query lint.
+    pub(crate) const fn new(kind: LintKind, range: Range<usize>) -> Self {
+        Self { kind, range }
+    }
+}
+
+impl QueryLint {
+    //! Properties
+
+    /// Gets the lint kind.
+    #[must_use]
+    pub const fn kind(&self) -> LintKind {
+        self.kind
+    }
+
+    /// Gets the byte range of the query string the lint refers to.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl Display for QueryLint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.range.start, self.range.end)
+    }
+}
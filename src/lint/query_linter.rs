@@ -0,0 +1,193 @@
+use crate::lint::LintKind::*;
+use crate::lint::QueryLint;
+use crate::{Query, percent};
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// A linter for the query params that back-end frameworks parse differently.
+///
+/// The [`Self::DEFAULT`] linter allows values up to 1024 bytes. It is configured with the `with_` methods, like the
+/// [`Normalizer`](crate::Normalizer).
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use web_url::WebUrl;
+/// use web_url::lint::{LintKind, QueryLint, QueryLinter};
+///
+/// let url: WebUrl = WebUrl::from_str("https://example.com/?id=1&ID=2&next=%252e%252e").unwrap();
+/// let lints: Vec<QueryLint> = QueryLinter::DEFAULT.lint(url.query().unwrap());
+///
+/// assert_eq!(lints[0].kind(), LintKind::AmbiguousName);
+/// assert_eq!(&url.query().unwrap().as_str()[lints[0].range()], "ID");
+/// assert_eq!(lints[1].kind(), LintKind::DoubleEncoding);
+/// ```
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct QueryLinter {
+    max_value_len: usize,
+}
+
+impl QueryLinter {
+    //! Constants
+
+    /// The default linter. (see [`QueryLinter`])
+    pub const DEFAULT: Self = Self { max_value_len: 1024 };
+}
+
+impl Default for QueryLinter {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl QueryLinter {
+    //! Builders
+
+    /// Sets the max value length in bytes. The value is measured as it is written in the query, so it is encoded.
+    pub const fn with_max_value_len(mut self, max_value_len: usize) -> Self {
+        self.max_value_len = max_value_len;
+        self
+    }
+}
+
+impl QueryLinter {
+    //! Lint
+
+    /// Lints the params of the `query`.
+    ///
+    /// The lints are in the order of their params. The duplicate & ambiguous name lints refer to the later name, & the
+    /// first name is the one it duplicates. A name is compared to the earlier names after it is decoded as
+    /// `application/x-www-form-urlencoded` & lowercased, so `a+b` & `A%20B` are ambiguous.
+    #[must_use]
+    pub fn lint(&self, query: Query) -> Vec<QueryLint> {
+        let mut lints: Vec<QueryLint> = Vec::new();
+        let mut names: HashSet<&str> = HashSet::new();
+        let mut folded_names: HashSet<String> = HashSet::new();
+
+        // The first param starts after the '?' & every param after its '&'.
+        let mut start: usize = 1;
+        for param in query.iter_params() {
+            let name: &str = param.name();
+            let end: usize = start + name.len() + param.value().map_or(0, |value| value.len() + 1);
+
+            if name.is_empty() {
+                if param.value().is_some() {
+                    lints.push(QueryLint::new(EmptyName, start..end));
+                }
+            } else {
+                let folded: String = Self::fold(name);
+                let name_range: Range<usize> = start..(start + name.len());
+                if !names.insert(name) {
+                    lints.push(QueryLint::new(DuplicateName, name_range));
+                } else if folded_names.contains(&folded) {
+                    lints.push(QueryLint::new(AmbiguousName, name_range));
+                }
+                folded_names.insert(folded);
+            }
+
+            Self::lint_chars(&query.as_str()[start..end], start, &mut lints);
+
+            if let Some(value) = param.value()
+                && value.len() > self.max_value_len
+            {
+                lints.push(QueryLint::new(OverlongValue, (end - value.len())..end));
+            }
+            start = end + 1;
+        }
+        lints
+    }
+
+    /// Decodes & lowercases the `name` for comparison.
+    fn fold(name: &str) -> String {
+        match percent::decode_form(name) {
+            Ok(decoded) => decoded.to_lowercase(),
+            Err(_) => name.to_lowercase(),
+        }
+    }
+
+    /// Lints the chars of the `param` that starts at the `offset` of the query.
+    fn lint_chars(param: &str, offset: usize, lints: &mut Vec<QueryLint>) {
+        let bytes: &[u8] = param.as_bytes();
+        for (index, c) in bytes.iter().enumerate() {
+            let start: usize = offset + index;
+            if *c == b';' {
+                lints.push(QueryLint::new(Semicolon, start..(start + 1)));
+            } else if *c == b'%' {
+                match bytes.get((index + 1)..(index + 3)) {
+                    Some([b'2', b'6'] | [b'3', b'D' | b'd']) => {
+                        lints.push(QueryLint::new(EncodedSeparator, start..(start + 3)));
+                    }
+                    Some([b'2', b'5'])
+                        if bytes
+                            .get((index + 3)..(index + 5))
+                            .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)) =>
+                    {
+                        lints.push(QueryLint::new(DoubleEncoding, start..(start + 5)));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Query;
+    use crate::lint::LintKind::*;
+    use crate::lint::{LintKind, QueryLint, QueryLinter};
+
+    #[test]
+    fn lint() {
+        let test_cases: &[(&str, &[(LintKind, &str)])] = &[
+            ("?", &[]),
+            ("?a=1&b=2&c", &[]),
+            ("?a=1&a=2", &[(DuplicateName, "a")]),
+            ("?a&a&a=", &[(DuplicateName, "a"), (DuplicateName, "a")]),
+            ("?id=1&ID=2", &[(AmbiguousName, "ID")]),
+            ("?a+b=1&A%20B=2", &[(AmbiguousName, "A%20B")]),
+            ("?a%2Eb=1&a.b=2", &[(AmbiguousName, "a.b")]),
+            (
+                "?q=a%26admin%3Dtrue",
+                &[(EncodedSeparator, "%26"), (EncodedSeparator, "%3D")],
+            ),
+            ("?a=1;b=2", &[(Semicolon, ";")]),
+            (
+                "?p=%252e%252e%252F",
+                &[
+                    (DoubleEncoding, "%252e"),
+                    (DoubleEncoding, "%252e"),
+                    (DoubleEncoding, "%252F"),
+                ],
+            ),
+            ("?p=%25zz&q=%25", &[]),
+            ("?=1", &[(EmptyName, "=1")]),
+            ("?&&", &[]),
+            ("?a=1&=&b", &[(EmptyName, "=")]),
+        ];
+        for (input, expected) in test_cases {
+            let query: Query = Query::new(input).unwrap();
+            let lints: Vec<QueryLint> = QueryLinter::DEFAULT.lint(query);
+            let lints: Vec<(LintKind, &str)> = lints.iter().map(|lint| (lint.kind(), &input[lint.range()])).collect();
+            assert_eq!(lints, *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn lint_overlong_value() {
+        let query: Query = Query::new("?a=12345&b=1234&c").unwrap();
+        let lints: Vec<QueryLint> = QueryLinter::DEFAULT.with_max_value_len(4).lint(query);
+        assert_eq!(lints, vec![QueryLint::new(OverlongValue, 3..8)]);
+        assert!(QueryLinter::DEFAULT.lint(query).is_empty());
+    }
+
+    #[test]
+    fn lint_range() {
+        let query: Query = Query::new("?a=1&b=2&b=3").unwrap();
+        let lints: Vec<QueryLint> = QueryLinter::DEFAULT.lint(query);
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].range(), 9..10);
+        assert_eq!(lints[0].to_string(), "duplicate name at 9..10");
+    }
+}
@@ -0,0 +1,7 @@
+pub use normalizer::*;
+pub use parse_limits::*;
+
+mod normalizer;
+mod parse_limits;
+
+mod parse;
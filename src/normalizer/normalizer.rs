@@ -0,0 +1,211 @@
+use crate::ParseLimits;
+
+/// A web-based URL normalization profile.
+///
+/// A normalizer selects the optional normalization steps that are applied when a URL is parsed. The structural
+/// normalization is always applied since every `WebUrl` depends on it: the scheme & host are lowercased, an IP address
+/// host is rewritten in its canonical form, a Unicode domain host is converted to its A-label form, an empty port is
+/// dropped along with its ':', a port with leading zeros is rewritten, & a URL parsed without a path gets the path '/'.
+///
+/// The optional steps are:
+/// - `remove_dot_segments`: removes the path dot-segments. (`/a/../b` -> `/b`)
+/// - `remove_default_port`: drops the default port of the scheme. (`http://host:80/` -> `http://host/`)
+/// - `normalize_percent`: decodes the escaped unreserved chars & uppercases the hex digits of the other escapes in the
+///   path, query, & fragment. (`/%7e%2f` -> `/~%2F`)
+/// - `remove_empty_query`: drops a query that is just a '?'. (`/p?` -> `/p`)
+/// - `remove_empty_fragment`: drops a fragment that is just a '#'. (`/p#` -> `/p`)
+/// - `remove_trailing_dot`: accepts a domain host with a trailing dot & strips it. (`host.` -> `host`) A domain host
+///   with a trailing dot is rejected when this is off since it is not a valid domain name.
+/// - `collapse_slashes`: collapses the duplicate slashes in the path. (`/a//b` -> `/a/b`) The empty segments are
+///   collapsed before the dot-segments are removed.
+///
+/// The [`ParseLimits`] are checked before a URL is parsed. Every profile is [`ParseLimits::UNLIMITED`] until limits are
+/// set with [`Self::with_limits`].
+///
+/// The [`Self::DEFAULT`] profile is used by `FromStr` & `TryFrom<String>`.
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Normalizer {
+    user_info: bool,
+    remove_dot_segments: bool,
+    remove_default_port: bool,
+    normalize_percent: bool,
+    remove_empty_query: bool,
+    remove_empty_fragment: bool,
+    remove_trailing_dot: bool,
+    collapse_slashes: bool,
+    limits: ParseLimits,
+}
+
+impl Normalizer {
+    //! Constants
+
+    /// The default profile.
+    ///
+    /// The dot-segments are removed, the default port is dropped, & the percent-encoding is normalized. These steps
+    /// never change what the URL refers to.
+    pub const DEFAULT: Self = Self {
+        user_info: false,
+        remove_dot_segments: true,
+        remove_default_port: true,
+        normalize_percent: true,
+        remove_empty_query: false,
+        remove_empty_fragment: false,
+        remove_trailing_dot: false,
+        collapse_slashes: false,
+        limits: ParseLimits::UNLIMITED,
+    };
+
+    /// The minimal profile.
+    ///
+    /// Only the structural normalization is applied, so a URL that is already in the structural form is preserved
+    /// byte for byte.
+    pub const MINIMAL: Self = Self {
+        user_info: false,
+        remove_dot_segments: false,
+        remove_default_port: false,
+        normalize_percent: false,
+        remove_empty_query: false,
+        remove_empty_fragment: false,
+        remove_trailing_dot: false,
+        collapse_slashes: false,
+        limits: ParseLimits::UNLIMITED,
+    };
+
+    /// The aggressive profile.
+    ///
+    /// Every step is applied. The empty query & fragment removal, the trailing dot stripping, & the slash collapsing
+    /// can change what the URL refers to for some servers, so this profile suits deduplication rather than requests.
+    pub const AGGRESSIVE: Self = Self {
+        user_info: false,
+        remove_dot_segments: true,
+        remove_default_port: true,
+        normalize_percent: true,
+        remove_empty_query: true,
+        remove_empty_fragment: true,
+        remove_trailing_dot: true,
+        collapse_slashes: true,
+        limits: ParseLimits::UNLIMITED,
+    };
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Normalizer {
+    //! Properties
+
+    /// Checks if the user info is accepted when parsing.
+    #[must_use]
+    pub const fn user_info(self) -> bool {
+        self.user_info
+    }
+
+    /// Checks if the path dot-segments are removed.
+    #[must_use]
+    pub const fn remove_dot_segments(self) -> bool {
+        self.remove_dot_segments
+    }
+
+    /// Checks if the default port of the scheme is dropped.
+    #[must_use]
+    pub const fn remove_default_port(self) -> bool {
+        self.remove_default_port
+    }
+
+    /// Checks if the percent-encoding of the path, query, & fragment is normalized.
+    #[must_use]
+    pub const fn normalize_percent(self) -> bool {
+        self.normalize_percent
+    }
+
+    /// Checks if a query that is just a '?' is dropped.
+    #[must_use]
+    pub const fn remove_empty_query(self) -> bool {
+        self.remove_empty_query
+    }
+
+    /// Checks if a fragment that is just a '#' is dropped.
+    #[must_use]
+    pub const fn remove_empty_fragment(self) -> bool {
+        self.remove_empty_fragment
+    }
+
+    /// Checks if the trailing dot of a domain host is stripped.
+    #[must_use]
+    pub const fn remove_trailing_dot(self) -> bool {
+        self.remove_trailing_dot
+    }
+
+    /// Checks if the duplicate slashes in the path are collapsed.
+    #[must_use]
+    pub const fn collapse_slashes(self) -> bool {
+        self.collapse_slashes
+    }
+
+    /// Gets the parse limits.
+    pub const fn limits(self) -> ParseLimits {
+        self.limits
+    }
+}
+
+impl Normalizer {
+    //! Builders
+
+    /// Sets whether the user info is accepted when parsing.
+    pub const fn with_user_info(mut self, user_info: bool) -> Self {
+        self.user_info = user_info;
+        self
+    }
+
+    /// Sets whether the path dot-segments are removed.
+    pub const fn with_remove_dot_segments(mut self, remove_dot_segments: bool) -> Self {
+        self.remove_dot_segments = remove_dot_segments;
+        self
+    }
+
+    /// Sets whether the default port of the scheme is dropped.
+    pub const fn with_remove_default_port(mut self, remove_default_port: bool) -> Self {
+        self.remove_default_port = remove_default_port;
+        self
+    }
+
+    /// Sets whether the percent-encoding of the path, query, & fragment is normalized.
+    pub const fn with_normalize_percent(mut self, normalize_percent: bool) -> Self {
+        self.normalize_percent = normalize_percent;
+        self
+    }
+
+    /// Sets whether a query that is just a '?' is dropped.
+    pub const fn with_remove_empty_query(mut self, remove_empty_query: bool) -> Self {
+        self.remove_empty_query = remove_empty_query;
+        self
+    }
+
+    /// Sets whether a fragment that is just a '#' is dropped.
+    pub const fn with_remove_empty_fragment(mut self, remove_empty_fragment: bool) -> Self {
+        self.remove_empty_fragment = remove_empty_fragment;
+        self
+    }
+
+    /// Sets whether the trailing dot of a domain host is stripped.
+    pub const fn with_remove_trailing_dot(mut self, remove_trailing_dot: bool) -> Self {
+        self.remove_trailing_dot = remove_trailing_dot;
+        self
+    }
+
+    /// Sets whether the duplicate slashes in the path are collapsed.
+    pub const fn with_collapse_slashes(mut self, collapse_slashes: bool) -> Self {
+        self.collapse_slashes = collapse_slashes;
+        self
+    }
+
+    /// Sets the parse limits.
+    pub const fn with_limits(mut self, limits: ParseLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
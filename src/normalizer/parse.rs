@@ -0,0 +1,207 @@
+use crate::Error::UrlTooLong;
+use crate::parse::{Offsets, parse_ref, parse_str, parse_string, rewrite_whatwg};
+use crate::{Error, InvalidUrlError, Normalizer, ParseLimits, WebUrl, WebUrlRef};
+
+impl Normalizer {
+    //! Parsing
+
+    /// Parses the web-based URL `s` & normalizes it with this profile.
+    pub fn parse(self, s: &str) -> Result<WebUrl, Error> {
+        parse_str(s, self)
+    }
+
+    /// Parses the web-based URL `s` & normalizes it with this profile.
+    ///
+    /// This is `parse` for an owned string. It reuses the allocation when the URL is already normalized & recovers the
+    /// original string on error, like `TryFrom<String>`.
+    pub fn parse_string(self, s: String) -> Result<WebUrl, InvalidUrlError> {
+        parse_string(s, self)
+    }
+
+    /// Parses the web-based URL `s` the way a browser does & normalizes it with this profile.
+    ///
+    /// The URL is parsed with the WHATWG URL Standard rather than RFC 3986, so the inputs a browser accepts are
+    /// accepted: the leading & trailing spaces & controls are trimmed, the tabs & newlines are removed, a '\\' is a
+    /// '/', any number of slashes may follow the scheme, the host is percent-decoded & may be a shorthand IPv4 address
+    /// (`127.1` or `0x7f.0.0.1`), & the chars the standard encodes are percent-encoded rather than rejected. The result
+    /// is then parsed & normalized like any other URL, so it is the same `WebUrl` as parsing the URL a browser shows.
+    ///
+    /// Only the special schemes, the schemes with a default port, are parsed with the standard. The URL of any other
+    /// scheme is trimmed & parsed as it is. The `file` scheme is special in the standard but a `WebUrl` needs a host,
+    /// so it is not parsed as special here.
+    ///
+    /// # WHATWG
+    /// <https://url.spec.whatwg.org/#concept-basic-url-parser>
+    pub fn parse_whatwg(self, s: &str) -> Result<WebUrl, Error> {
+        // The length is checked before the URL is rewritten into a new string. The rewritten URL is checked again.
+        if s.len() > self.limits().max_len() {
+            return Err(UrlTooLong);
+        }
+        parse_str(&rewrite_whatwg(s)?, self)
+    }
+
+    /// Parses the web-based URL `s` & borrows it without allocating.
+    ///
+    /// # Errors
+    /// Returns `NotNormalized` if `s` is a valid URL that is not already normalized with this profile, including its
+    /// letter case, since normalizing it would need a new string.
+    pub fn parse_ref(self, s: &str) -> Result<WebUrlRef<'_>, Error> {
+        let offsets: Offsets = parse_ref(s, self)?;
+        Ok(unsafe { WebUrlRef::from_offsets(s, offsets) })
+    }
+
+    /// Re-normalizes the `url` with this profile.
+    ///
+    /// The user info of the `url` is kept even when this profile does not accept it, & the parse limits are not
+    /// checked. A profile can only remove what another profile kept, so a URL normalized aggressively is unchanged by a
+    /// less aggressive profile.
+    pub fn normalize(self, url: WebUrl) -> WebUrl {
+        // The URL is valid & normalization never lengthens it, so re-parsing it cannot fail.
+        self.with_user_info(true)
+            .with_limits(ParseLimits::UNLIMITED)
+            .parse_string(url.into_string())
+            .expect("a valid web-url is valid for every normalizer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::InvalidHost;
+    use crate::{Error, Normalizer, WebUrl};
+    use std::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let default: Normalizer = Normalizer::DEFAULT;
+        let minimal: Normalizer = Normalizer::MINIMAL;
+        let aggressive: Normalizer = Normalizer::AGGRESSIVE;
+        let test_cases: &[(&str, Normalizer, Result<&str, Error>)] = &[
+            // The structural normalization is always applied.
+            ("HTTP://HOST:0443", minimal, Ok("http://host:443/")),
+            ("http://[0:0:0:0:0:0:0:1]:/p", minimal, Ok("http://[::1]/p")),
+            // The minimal profile preserves a URL already in the structural form.
+            (
+                "http://host:80/a/./b/../%7e%2f//?#",
+                minimal,
+                Ok("http://host:80/a/./b/../%7e%2f//?#"),
+            ),
+            (
+                "http://host:80/a/./b/../%7e%2f//?#",
+                default,
+                Ok("http://host/a/~%2F//?#"),
+            ),
+            (
+                "http://host:80/a/./b/../%7e%2f//?#",
+                aggressive,
+                Ok("http://host/a/~%2F/"),
+            ),
+            // Each step can be toggled alone.
+            (
+                "http://host/a/../b",
+                minimal.with_remove_dot_segments(true),
+                Ok("http://host/b"),
+            ),
+            (
+                "http://host:80/",
+                minimal.with_remove_default_port(true),
+                Ok("http://host/"),
+            ),
+            (
+                "http://host/%7e?%7e#%7e",
+                minimal.with_normalize_percent(true),
+                Ok("http://host/~?~#~"),
+            ),
+            (
+                "http://host/?",
+                minimal.with_remove_empty_query(true),
+                Ok("http://host/"),
+            ),
+            (
+                "http://host/?#",
+                minimal.with_remove_empty_query(true),
+                Ok("http://host/#"),
+            ),
+            (
+                "http://host/#",
+                minimal.with_remove_empty_fragment(true),
+                Ok("http://host/"),
+            ),
+            (
+                "http://host.com./p",
+                minimal.with_remove_trailing_dot(true),
+                Ok("http://host.com/p"),
+            ),
+            (
+                "http://HOST.com.:8080",
+                minimal.with_remove_trailing_dot(true),
+                Ok("http://host.com:8080/"),
+            ),
+            #[cfg(feature = "idna")]
+            (
+                "http://B\u{fc}cher.example.:8080",
+                minimal.with_remove_trailing_dot(true),
+                Ok("http://xn--bcher-kva.example:8080/"),
+            ),
+            (
+                "http://host//a//b",
+                minimal.with_collapse_slashes(true),
+                Ok("http://host/a/b"),
+            ),
+            // A domain host with a trailing dot is not valid unless the dot is stripped.
+            ("http://host.com./p", default, Err(InvalidHost)),
+            ("http://b\u{fc}cher.example./p", default, Err(InvalidHost)),
+            ("http://./p", aggressive, Err(InvalidHost)),
+            ("http://[::1]./p", aggressive, Err(InvalidHost)),
+            // The user info is only accepted when opted into.
+            (
+                "http://user@host/",
+                default.with_user_info(true),
+                Ok("http://user@host/"),
+            ),
+            (
+                "http://user@host./",
+                aggressive.with_user_info(true),
+                Ok("http://user@host/"),
+            ),
+        ];
+        for (input, normalizer, expected) in test_cases {
+            let result: Result<WebUrl, Error> = normalizer.parse(input);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "input={}", input),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "input={}", input),
+            }
+
+            let result: Result<WebUrl, Error> = normalizer.parse_string(input.to_string()).map_err(Error::from);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "input={}", input),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "input={}", input),
+            }
+        }
+
+        // The default profile is the one `FromStr` uses.
+        let input: &str = "HTTP://host:80/a/../%7e?#";
+        assert_eq!(Normalizer::default(), Normalizer::DEFAULT);
+        assert_eq!(default.parse(input).unwrap(), WebUrl::from_str(input).unwrap());
+    }
+
+    #[test]
+    fn normalize() -> Result<(), Box<dyn std::error::Error>> {
+        let url: WebUrl = Normalizer::MINIMAL
+            .with_user_info(true)
+            .parse("http://u@host:80/a/../%7e?")?;
+        assert_eq!(url.as_str(), "http://u@host:80/a/../%7e?");
+        assert_eq!(url.path().as_str(), "/a/../%7e");
+
+        let url: WebUrl = Normalizer::DEFAULT.normalize(url);
+        assert_eq!(url.as_str(), "http://u@host/~?");
+
+        let url: WebUrl = Normalizer::AGGRESSIVE.normalize(url);
+        assert_eq!(url.as_str(), "http://u@host/~");
+
+        // A less aggressive profile never undoes a step.
+        let url: WebUrl = Normalizer::MINIMAL.normalize(url);
+        assert_eq!(url.as_str(), "http://u@host/~");
+
+        Ok(())
+    }
+}
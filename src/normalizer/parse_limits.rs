@@ -0,0 +1,139 @@
+/// The resource limits for parsing untrusted URLs.
+///
+/// The limits are checked against the URL as it is written, before it is validated or normalized & before anything is
+/// allocated, so a hostile URL is rejected for the cost of one scan. Each limit fails with its own error:
+/// - `max_len`: the URL length in bytes. (`UrlTooLong`)
+/// - `max_host_len`: the host length in bytes, without the user info & port. (`HostTooLong`)
+/// - `max_path_segments`: the number of path segments, which is the number of '/' chars in the path.
+///   (`TooManyPathSegments`)
+/// - `max_segment_len`: the length of each path segment in bytes, without its '/'. (`PathSegmentTooLong`)
+/// - `max_params`: the number of query params, which is one more than the number of '&' chars in the query.
+///   (`TooManyParams`)
+/// - `max_fragment_len`: the fragment length in bytes, without its '#'. (`FragmentTooLong`)
+///
+/// The lengths are of the input, so a percent-encoded or Unicode part counts the bytes it is written with. The limits
+/// are set on a [`Normalizer`](crate::Normalizer) with `with_limits`, & every `Normalizer` constant is
+/// [`Self::UNLIMITED`].
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ParseLimits {
+    max_len: usize,
+    max_host_len: usize,
+    max_path_segments: usize,
+    max_segment_len: usize,
+    max_params: usize,
+    max_fragment_len: usize,
+}
+
+impl ParseLimits {
+    //! Constants
+
+    /// The limits that never fail. (the URL must still be under 4 GiB)
+    pub const UNLIMITED: Self = Self {
+        max_len: usize::MAX,
+        max_host_len: usize::MAX,
+        max_path_segments: usize::MAX,
+        max_segment_len: usize::MAX,
+        max_params: usize::MAX,
+        max_fragment_len: usize::MAX,
+    };
+
+    /// The limits for a public gateway, which are well above the URLs that browsers & servers send.
+    ///
+    /// The URL is at most 8 KiB, the host 512 bytes, the path 128 segments of 2 KiB, the query 256 params, & the
+    /// fragment 2 KiB.
+    pub const RECOMMENDED: Self = Self {
+        max_len: 8 * 1024,
+        max_host_len: 512,
+        max_path_segments: 128,
+        max_segment_len: 2 * 1024,
+        max_params: 256,
+        max_fragment_len: 2 * 1024,
+    };
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+impl ParseLimits {
+    //! Properties
+
+    /// Gets the max URL length in bytes.
+    #[must_use]
+    pub const fn max_len(self) -> usize {
+        self.max_len
+    }
+
+    /// Gets the max host length in bytes.
+    #[must_use]
+    pub const fn max_host_len(self) -> usize {
+        self.max_host_len
+    }
+
+    /// Gets the max number of path segments.
+    #[must_use]
+    pub const fn max_path_segments(self) -> usize {
+        self.max_path_segments
+    }
+
+    /// Gets the max path segment length in bytes.
+    #[must_use]
+    pub const fn max_segment_len(self) -> usize {
+        self.max_segment_len
+    }
+
+    /// Gets the max number of query params.
+    #[must_use]
+    pub const fn max_params(self) -> usize {
+        self.max_params
+    }
+
+    /// Gets the max fragment length in bytes.
+    #[must_use]
+    pub const fn max_fragment_len(self) -> usize {
+        self.max_fragment_len
+    }
+}
+
+impl ParseLimits {
+    //! Builders
+
+    /// Sets the max URL length in bytes.
+    pub const fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sets the max host length in bytes.
+    pub const fn with_max_host_len(mut self, max_host_len: usize) -> Self {
+        self.max_host_len = max_host_len;
+        self
+    }
+
+    /// Sets the max number of path segments.
+    pub const fn with_max_path_segments(mut self, max_path_segments: usize) -> Self {
+        self.max_path_segments = max_path_segments;
+        self
+    }
+
+    /// Sets the max path segment length in bytes.
+    pub const fn with_max_segment_len(mut self, max_segment_len: usize) -> Self {
+        self.max_segment_len = max_segment_len;
+        self
+    }
+
+    /// Sets the max number of query params.
+    pub const fn with_max_params(mut self, max_params: usize) -> Self {
+        self.max_params = max_params;
+        self
+    }
+
+    /// Sets the max fragment length in bytes.
+    pub const fn with_max_fragment_len(mut self, max_fragment_len: usize) -> Self {
+        self.max_fragment_len = max_fragment_len;
+        self
+    }
+}
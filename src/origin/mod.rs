@@ -0,0 +1,5 @@
+pub use origin::*;
+pub use tuple_origin::*;
+
+mod origin;
+mod tuple_origin;
@@ -0,0 +1,224 @@
+use crate::Error::{InvalidFragment, InvalidPath, InvalidQuery, InvalidScheme};
+use crate::{Error, TupleOrigin, WebUrl};
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// The origin of a web-based URL, which is the security boundary browsers use for CORS & CSRF checks.
+///
+/// A URL with a special scheme (`http`, `https`, `ws`, `wss`, or `ftp`) has a tuple origin of its scheme, host, &
+/// effective port. Any other URL has an opaque origin, which is serialized as `null`.
+///
+/// The origin is serialized as the `Origin` header value & is parsed from one. Two origins are equal when their
+/// serializations are equal, so the opaque origins are all equal to each other. Use [`Self::same_origin`] for the
+/// same-origin check, which an opaque origin never passes.
+///
+/// # WHATWG
+/// <https://html.spec.whatwg.org/multipage/browsers.html#origin>
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Origin {
+    /// A scheme, host, & port tuple.
+    Tuple(TupleOrigin),
+
+    /// An opaque origin. (serialized as `null`)
+    Opaque,
+}
+
+impl Origin {
+    //! Properties
+
+    /// Checks if the origin is opaque.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        matches!(self, Self::Opaque)
+    }
+
+    /// Gets the optional tuple origin. (`None` when the origin is opaque)
+    #[must_use]
+    pub const fn as_tuple(&self) -> Option<&TupleOrigin> {
+        match self {
+            Self::Tuple(tuple) => Some(tuple),
+            Self::Opaque => None,
+        }
+    }
+}
+
+impl Origin {
+    //! Comparison
+
+    /// Checks if this origin is the same origin as the `other` origin.
+    ///
+    /// The tuple origins are the same origin when their schemes, hosts, & ports are equal. An opaque origin is never
+    /// the same origin as another origin since its URL is not known, even when both are `null`.
+    ///
+    /// # WHATWG
+    /// <https://html.spec.whatwg.org/multipage/browsers.html#same-origin>
+    #[must_use]
+    pub fn same_origin(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Tuple(tuple), Self::Tuple(other)) => tuple == other,
+            _ => false,
+        }
+    }
+}
+
+impl From<&WebUrl> for Origin {
+    fn from(url: &WebUrl) -> Self {
+        url.origin()
+    }
+}
+
+impl FromStr for Origin {
+    type Err = Error;
+
+    /// Parses the `Origin` header value `s`.
+    ///
+    /// The value is `null` or a URL with a special scheme & no path, query, or fragment. It is normalized like a
+    /// parsed URL, so the letter case is ignored & the default port is dropped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "null" {
+            return Ok(Self::Opaque);
+        }
+
+        // The scheme is validated by the URL parser, so a value without "://" fails there.
+        let after_scheme: &str = s.split_once("://").map(|(_, after)| after).unwrap_or_default();
+        if let Some(index) = after_scheme.find(['/', '?', '#']) {
+            return Err(match after_scheme.as_bytes()[index] {
+                b'/' => InvalidPath,
+                b'?' => InvalidQuery,
+                _ => InvalidFragment,
+            });
+        }
+        match WebUrl::from_str(s)?.origin() {
+            Self::Opaque => Err(InvalidScheme),
+            origin => Ok(origin),
+        }
+    }
+}
+
+impl TryFrom<&str> for Origin {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl Debug for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tuple(tuple) => Display::fmt(tuple, f),
+            Self::Opaque => f.write_str("null"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::{InvalidFragment, InvalidHost, InvalidPath, InvalidQuery, InvalidScheme, UserInfoNotSupported};
+    use crate::{Error, Origin, TupleOrigin, WebUrl};
+    use address::{HostRef, IPv6Address};
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    #[test]
+    fn web_url_origin() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, &str)] = &[
+            ("https://example.com/a?b#c", "https://example.com"),
+            ("https://EXAMPLE.com:443/", "https://example.com"),
+            ("http://example.com:8080/", "http://example.com:8080"),
+            ("ws://[::1]:80/", "ws://[::1]"),
+            ("wss://127.0.0.1:8443/", "wss://127.0.0.1:8443"),
+            ("ftp://files.example.com/", "ftp://files.example.com"),
+            ("custom://example.com/", "null"),
+            ("file://host/etc/hosts", "null"),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(url.origin().to_string(), *expected, "input={}", input);
+            assert_eq!(Origin::from(&url), url.origin(), "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn tuple() -> Result<(), Box<dyn std::error::Error>> {
+        let origin: Origin = WebUrl::from_str("https://[::1]/")?.origin();
+        let tuple: &TupleOrigin = origin.as_tuple().unwrap();
+        assert_eq!(tuple.scheme(), "https");
+        assert_eq!(tuple.host(), HostRef::Address(IPv6Address::LOCALHOST.to_ip()));
+        assert_eq!(tuple.port(), 443);
+        assert!(!origin.is_opaque());
+
+        assert!(Origin::Opaque.as_tuple().is_none());
+        assert!(Origin::Opaque.is_opaque());
+        Ok(())
+    }
+
+    #[test]
+    fn same_origin() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, &str, bool)] = &[
+            ("https://example.com/a", "https://example.com/b?c", true),
+            ("https://example.com/", "https://example.com:443/", true),
+            ("https://example.com/", "http://example.com/", false),
+            ("https://example.com/", "https://www.example.com/", false),
+            ("https://example.com/", "https://example.com:8443/", false),
+            // An opaque origin is not the same origin as itself.
+            ("custom://example.com/", "custom://example.com/", false),
+        ];
+        for (a, b, expected) in test_cases {
+            let a: Origin = WebUrl::from_str(a)?.origin();
+            let b: Origin = WebUrl::from_str(b)?.origin();
+            assert_eq!(a.same_origin(&b), *expected, "a={} b={}", a, b);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_str() {
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("https://example.com", Ok("https://example.com")),
+            ("HTTPS://Example.com:443", Ok("https://example.com")),
+            ("http://localhost:3000", Ok("http://localhost:3000")),
+            ("http://[0:0::1]:80", Ok("http://[::1]")),
+            ("null", Ok("null")),
+            ("https://example.com/", Err(InvalidPath)),
+            ("https://example.com?q", Err(InvalidQuery)),
+            ("https://example.com#f", Err(InvalidFragment)),
+            ("https://user@example.com", Err(UserInfoNotSupported)),
+            ("custom://example.com", Err(InvalidScheme)),
+            ("example.com", Err(InvalidScheme)),
+            ("https://", Err(InvalidHost)),
+            ("Null", Err(InvalidScheme)),
+        ];
+        for (input, expected) in test_cases {
+            let result: Result<Origin, Error> = Origin::from_str(input);
+            assert_eq!(
+                result.map(|origin| origin.to_string()).as_deref(),
+                expected.as_deref(),
+                "input={}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn hash() -> Result<(), Box<dyn std::error::Error>> {
+        let origins: HashSet<Origin> = [
+            "https://example.com/a",
+            "https://example.com:443/b",
+            "http://example.com/",
+        ]
+        .iter()
+        .map(|url| WebUrl::from_str(url).map(|url| url.origin()))
+        .collect::<Result<_, _>>()?;
+        assert_eq!(origins.len(), 2);
+        assert!(origins.contains(&Origin::from_str("https://example.com")?));
+        Ok(())
+    }
+}
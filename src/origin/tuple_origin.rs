@@ -0,0 +1,74 @@
+use crate::Scheme;
+use crate::parse::CanonicalHost;
+use address::{Host, HostRef};
+use std::fmt::{Debug, Display, Formatter};
+
+/// The origin of a URL with a special scheme: its scheme, host, & effective port.
+///
+/// # WHATWG
+/// <https://html.spec.whatwg.org/multipage/browsers.html#concept-origin-tuple>
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TupleOrigin {
+    scheme: Scheme<'static>,
+    host: Host,
+    port: u16,
+}
+
+impl TupleOrigin {
+    //! Construction
+
+    /// Creates a new tuple origin with the `host` & the optional `port`.
+    ///
+    /// Returns `None` if the `scheme` is not special, which is a scheme with a default port. The `port` defaults to the
+    /// default port of the scheme.
+    pub(crate) fn new(scheme: Scheme, host: HostRef, port: Option<u16>) -> Option<Self> {
+        let scheme: Scheme<'static> = scheme.with_default_port()?;
+        let port: u16 = port.or(scheme.default_port())?;
+        Some(Self {
+            scheme,
+            host: host.into(),
+            port,
+        })
+    }
+}
+
+impl TupleOrigin {
+    //! Properties
+
+    /// Gets the scheme.
+    pub const fn scheme(&self) -> Scheme<'static> {
+        self.scheme
+    }
+
+    /// Gets the host reference.
+    pub fn host(&self) -> HostRef<'_> {
+        HostRef::from(&self.host)
+    }
+
+    /// Gets the effective port. (the port, or the default port of the scheme when the URL had no port)
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl Debug for TupleOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for TupleOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The default port is omitted like it is in a normalized URL. An IPv6 address host is bracketed.
+        write!(f, "{}://", self.scheme)?;
+        match &self.host {
+            Host::Name(domain) => f.write_str(domain.name())?,
+            Host::Address(ip) => f.write_str(CanonicalHost::new(*ip).as_str())?,
+        }
+        if Some(self.port) != self.scheme.default_port() {
+            write!(f, ":{}", self.port)?;
+        }
+        Ok(())
+    }
+}
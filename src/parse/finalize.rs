@@ -1,27 +1,31 @@
 use crate::Error;
 use crate::Error::UrlTooLong;
 use crate::WebUrl;
-use crate::parse::{PathPlus, PrePath};
+use crate::parse::{Parts, PathPlus, PrePath, make_percent_uppercase};
 use address::IPAddress;
 
-/// Finalizes the web-based URL from the pre-path & path-plus parts.
+/// Finalizes the web-based URL from the parsed parts.
 ///
-/// The `url` must already be normalized apart from the letter case, which is normalized here.
+/// The `url` must already be normalized apart from the letter case, which is normalized here. This includes the case of
+/// the percent-encoded hex digits when the percent-encoding is normalized.
 ///
 /// # Safety
-/// The given URL must match the given pre-path & path-plus parts.
-pub unsafe fn finalize_web_url(
-    mut url: String,
-    pre_path: PrePath,
-    path_plus: PathPlus,
-) -> Result<WebUrl, (Error, String)> {
+/// The given URL must match the given parts.
+pub unsafe fn finalize_web_url(mut url: String, parts: Parts) -> Result<WebUrl, (Error, String)> {
     if url.len() > WebUrl::MAX_LEN {
         return Err((UrlTooLong, url));
     }
 
+    let pre_path: PrePath = parts.pre_path;
+    let path_plus: PathPlus = parts.path_plus;
+
     pre_path.make_lowercase(url.as_mut_str());
+    if parts.normalizer.normalize_percent() {
+        make_percent_uppercase(&mut url[pre_path.canonical_len()..]);
+    }
 
     let scheme_len: u32 = pre_path.scheme_len as u32;
+    let host_start: u32 = pre_path.host_start() as u32;
 
     // The canonical host end is used since `url` is the normalized URL, in which an IP address host may be shorter or
     // longer than it was in the parsed URL.
@@ -33,7 +37,11 @@ pub unsafe fn finalize_web_url(
     // the parsed URL.
     let port_end: u32 = pre_path.canonical_len() as u32;
     let path_end: u32 = port_end + (path_plus.canonical_path_len as u32);
-    let query_end: u32 = path_end + (path_plus.query_len as u32);
+    let query_end: u32 = path_end + (path_plus.canonical_query_len as u32);
 
-    Ok(unsafe { WebUrl::new_unchecked(url, scheme_len, host_end, ip, port_end, port, path_end, query_end) })
+    Ok(unsafe {
+        WebUrl::new_unchecked_with_host_start(
+            url, scheme_len, host_start, host_end, ip, port_end, port, path_end, query_end,
+        )
+    })
 }
@@ -0,0 +1,103 @@
+use crate::Fixup::{AddedScheme, EncodedChars, FixedSlashes, TrimmedWhitespace};
+use crate::parse::{is_authority_end, is_valid_char, parse_scheme_len};
+use crate::{Fixup, Scheme};
+use std::fmt::Write;
+
+/// Fixes the user-typed URL `s` so it can be parsed as a web-based URL & gets the fixups that were applied.
+///
+/// The fixed URL is not validated, so it may still be invalid.
+pub fn fix_up(s: &str, default_scheme: Scheme) -> (String, Vec<Fixup>) {
+    let mut fixups: Vec<Fixup> = Vec::new();
+    let trimmed: &str = s.trim();
+    if trimmed.len() != s.len() {
+        fixups.push(TrimmedWhitespace);
+    }
+
+    let mut url: String = String::with_capacity(default_scheme.as_str().len() + 3 + trimmed.len());
+    let colon: Option<usize> = trimmed.find(':');
+    let after_scheme: &str = if let Some(colon) = colon
+        && Scheme::default_port_ignore_case(&trimmed[..colon]).is_some()
+    {
+        // A scheme with a default port is followed by an authority, so its slashes can be fixed.
+        let rest: &str = &trimmed[colon + 1..];
+        let after_slashes: &str = rest.trim_start_matches(['/', '\\']);
+        if rest.len() - after_slashes.len() != 2 || !rest.starts_with("//") {
+            fixups.push(FixedSlashes);
+        }
+        url.push_str(&trimmed[..colon]);
+        after_slashes
+    } else if let Ok((scheme_len, rest)) = parse_scheme_len(trimmed) {
+        url.push_str(&trimmed[..scheme_len]);
+        rest
+    } else if let Some(colon) = colon
+        && is_opaque_scheme(trimmed, colon)
+    {
+        // A scheme with no authority, like `mailto:`, is not a web-based URL, so the URL is left for the parser to
+        // reject rather than being read as a host & port.
+        url.push_str(trimmed);
+        return (url, fixups);
+    } else {
+        // A "//" prefix is a scheme-relative URL. Otherwise the ':' is the port of a host like `localhost:3000`.
+        fixups.push(AddedScheme);
+        url.push_str(default_scheme.as_str());
+        trimmed.strip_prefix("//").unwrap_or(trimmed)
+    };
+    url.push_str("://");
+
+    // The authority is left to the host & port parsers. Only the path, query, & fragment chars are encoded.
+    let (authority, rest) = after_scheme.split_at(
+        after_scheme
+            .bytes()
+            .position(is_authority_end)
+            .unwrap_or(after_scheme.len()),
+    );
+    url.push_str(authority);
+    let (path_and_query, fragment) = rest.split_at(rest.find('#').unwrap_or(rest.len()));
+    let mut encoded: bool = write_encoded(path_and_query, &mut url);
+    if let Some(fragment) = fragment.strip_prefix('#') {
+        url.push('#');
+        encoded |= write_encoded(fragment, &mut url);
+    }
+    if encoded {
+        fixups.push(EncodedChars);
+    }
+    (url, fixups)
+}
+
+/// Checks if the `colon` of the user-typed URL `s` ends a scheme that has no authority, like `mailto:user`,
+/// `tel:+1-555-0100`, or `javascript:alert(1)`.
+///
+/// The ':' of a host & port, like `localhost:3000`, is followed by digits up to the end of the authority, so the ':'
+/// only ends a scheme when the text before it is a valid scheme & the text after it is not a port.
+fn is_opaque_scheme(s: &str, colon: usize) -> bool {
+    let after_colon: &str = &s[colon + 1..];
+    let port: &str = &after_colon[..after_colon
+        .bytes()
+        .position(is_authority_end)
+        .unwrap_or(after_colon.len())];
+    Scheme::is_valid_ignore_case(&s[..colon]) && !port.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// Percent-encodes the chars of `s` the URL does not accept & writes it to the `url`. A '%' is only encoded when it
+/// does not begin an escape.
+///
+/// Returns `true` if any char was encoded.
+fn write_encoded(s: &str, url: &mut String) -> bool {
+    let bytes: &[u8] = s.as_bytes();
+    let mut encoded: bool = false;
+    for (index, c) in bytes.iter().copied().enumerate() {
+        let valid: bool = if c == b'%' {
+            let hex: Option<&[u8]> = bytes.get(index + 1..index + 3);
+            hex.is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+        } else {
+            is_valid_char(c, "")
+        };
+        if valid {
+            url.push(c as char);
+        } else {
+            write!(url, "%{:02X}", c).expect("writing to a string never fails");
+            encoded = true;
+        }
+    }
+    encoded
+}
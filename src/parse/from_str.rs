@@ -1,21 +1,25 @@
-use crate::Error;
-use crate::WebUrl;
 use crate::parse::{Parts, finalize_web_url, parse_parts, write_normalized};
+use crate::{Error, Normalizer, WebUrl};
 use std::str::FromStr;
 
 impl FromStr for WebUrl {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Parts = parse_parts(s)?;
+        parse_str(s, Normalizer::DEFAULT)
+    }
+}
 
-        // The URL is validated before it is allocated, so invalid input never allocates & the normalized length is
-        // known exactly.
-        let mut url: String = String::with_capacity(parts.normalized_len(s.len()));
-        write_normalized(s, parts, &mut url);
+/// Parses the web-based URL `s` & normalizes it with the `normalizer`.
+pub fn parse_str(s: &str, normalizer: Normalizer) -> Result<WebUrl, Error> {
+    let parts: Parts = parse_parts(s, normalizer)?;
 
-        unsafe { finalize_web_url(url, parts.pre_path, parts.path_plus) }.map_err(|(error, _)| error)
-    }
+    // The URL is validated before it is allocated, so invalid input never allocates & the normalized length is known
+    // exactly.
+    let mut url: String = String::with_capacity(parts.normalized_len());
+    write_normalized(s, &parts, &mut url);
+
+    unsafe { finalize_web_url(url, parts) }.map_err(|(error, _)| error)
 }
 
 #[cfg(test)]
@@ -39,10 +43,17 @@ mod tests {
             ("http://host", Ok("http://host/")),
             ("http://host?q", Ok("http://host/?q")),
             ("http://host#f", Ok("http://host/#f")),
-            ("http://host:80", Ok("http://host:80/")),
+            ("http://host:8080", Ok("http://host:8080/")),
             // An empty port is dropped along with its ':' & the leading zeros are stripped.
             ("http://host:/p", Ok("http://host/p")),
-            ("http://host:0080/p", Ok("http://host:80/p")),
+            ("http://host:08080/p", Ok("http://host:8080/p")),
+            // The default port of the scheme is dropped.
+            ("http://host:80/p", Ok("http://host/p")),
+            ("HTTPS://host:0443?q", Ok("https://host/?q")),
+            ("wss://host:443/p", Ok("wss://host/p")),
+            ("ftp://host:21/p", Ok("ftp://host/p")),
+            ("http://host:443/p", Ok("http://host:443/p")),
+            ("s://host:80/p", Ok("s://host:80/p")),
             ("http://host:0/p", Ok("http://host:0/p")),
             // An IP address host is rewritten in its canonical form.
             ("http://[0:0:0:0:0:0:0:1]/p", Ok("http://[::1]/p")),
@@ -50,6 +61,10 @@ mod tests {
             // The path dot-segments are removed.
             ("http://host/a/../b", Ok("http://host/b")),
             ("http://host/a/./b/", Ok("http://host/a/b/")),
+            // The escaped unreserved chars are decoded & the other escapes are uppercased.
+            ("http://host/%7e%2f?%41%3d#%2e%3f", Ok("http://host/~%2F?A%3D#.%3F")),
+            ("http://host/a/%2E%2e/b", Ok("http://host/b")),
+            ("http://host/%3A?%3A#%3A", Ok("http://host/%3A?%3A#%3A")),
             // The error names the most specific part that can be blamed.
             ("", Err(InvalidScheme)),
             ("no-scheme", Err(InvalidScheme)),
@@ -83,8 +98,10 @@ mod tests {
             "http://host:8080/a/b?x=1&y=2#z",
             "http://host:0/p",
             "http://127.0.0.1/p",
-            "http://[::1]:80/p",
+            "http://[::1]:8080/p",
+            "https://host:80/p",
             "s://host/?#",
+            "http://host/~%2F?%3D#%3F",
         ];
         for url in test_cases {
             let parsed: WebUrl = WebUrl::from_str(url).unwrap();
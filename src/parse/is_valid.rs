@@ -33,7 +33,7 @@ pub const fn is_valid_char(c: u8, exclude: &str) -> bool {
         return false;
     }
 
-    // The `exclude` is scanned by hand since `<[u8]>::contains` is not const. It never holds more than two chars.
+    // The `exclude` is scanned by hand since `<[u8]>::contains` is not const. It never holds more than four chars.
     let exclude: &[u8] = exclude.as_bytes();
     let mut index: usize = 0;
     while index < exclude.len() {
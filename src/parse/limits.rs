@@ -0,0 +1,145 @@
+use crate::Error::{FragmentTooLong, HostTooLong, PathSegmentTooLong, TooManyParams, TooManyPathSegments, UrlTooLong};
+use crate::{Error, ParseLimits};
+
+/// Checks the URL `s` against the `limits` before it is parsed.
+///
+/// The parts are found with a lenient scan of the URL as it is written, so the limits are checked before any part is
+/// validated & nothing is allocated. A URL that is invalid is left for the parser to reject. The error has the span of
+/// the part that exceeded its limit.
+pub fn check_limits(s: &str, limits: ParseLimits) -> Result<(), (Error, (usize, usize))> {
+    if limits == ParseLimits::UNLIMITED {
+        return Ok(());
+    }
+    if s.len() > limits.max_len() {
+        return Err((UrlTooLong, (0, s.len())));
+    }
+
+    // The fragment starts at the first '#' & the query at the first '?' before it.
+    let fragment_start: usize = s.find('#').unwrap_or(s.len());
+    let query_start: usize = s[..fragment_start].find('?').unwrap_or(fragment_start);
+
+    // The authority follows the "//" after the scheme & the path follows the authority.
+    let mut path_start: usize = query_start;
+    if let Some(colon) = s[..query_start].find(':')
+        && s[(colon + 1)..query_start].starts_with("//")
+    {
+        let authority_start: usize = colon + 3;
+        let authority_end: usize = s[authority_start..query_start]
+            .find('/')
+            .map_or(query_start, |slash| authority_start + slash);
+        check_host(s, (authority_start, authority_end), limits)?;
+        path_start = authority_end;
+    }
+
+    check_path(s, (path_start, query_start), limits)?;
+    if query_start < fragment_start {
+        let params: usize = s[query_start..fragment_start].matches('&').count() + 1;
+        if params > limits.max_params() {
+            return Err((TooManyParams, (query_start + 1, fragment_start)));
+        }
+    }
+    if fragment_start < s.len() && s.len() - fragment_start - 1 > limits.max_fragment_len() {
+        return Err((FragmentTooLong, (fragment_start + 1, s.len())));
+    }
+    Ok(())
+}
+
+/// Checks the host in the `authority` span of the URL `s`.
+///
+/// The host follows the last '@' & ends at the ':' of the port, which is the last ':' unless the host is bracketed.
+fn check_host(s: &str, authority: (usize, usize), limits: ParseLimits) -> Result<(), (Error, (usize, usize))> {
+    let (start, end) = authority;
+    let start: usize = s[start..end].rfind('@').map_or(start, |at| start + at + 1);
+    let host: &str = &s[start..end];
+    let host_len: usize = if host.starts_with('[') {
+        host.find(']').map_or(host.len(), |bracket| bracket + 1)
+    } else {
+        host.rfind(':').unwrap_or(host.len())
+    };
+    if host_len > limits.max_host_len() {
+        return Err((HostTooLong, (start, start + host_len)));
+    }
+    Ok(())
+}
+
+/// Checks the segments of the `path` span of the URL `s`. Each segment follows a '/' char.
+fn check_path(s: &str, path: (usize, usize), limits: ParseLimits) -> Result<(), (Error, (usize, usize))> {
+    let (start, end) = path;
+    let mut segment_start: usize = start;
+    for (index, segment) in s[start..end].split('/').skip(1).enumerate() {
+        segment_start += 1;
+        if index >= limits.max_path_segments() {
+            return Err((TooManyPathSegments, (start, end)));
+        }
+        if segment.len() > limits.max_segment_len() {
+            return Err((PathSegmentTooLong, (segment_start, segment_start + segment.len())));
+        }
+        segment_start += segment.len();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::{
+        FragmentTooLong, HostTooLong, InvalidScheme, PathSegmentTooLong, TooManyParams, TooManyPathSegments, UrlTooLong,
+    };
+    use crate::{Error, Normalizer, ParseLimits, WebUrl};
+
+    #[test]
+    fn parse_limits() {
+        let limits: ParseLimits = ParseLimits::UNLIMITED
+            .with_max_len(64)
+            .with_max_host_len(11)
+            .with_max_path_segments(3)
+            .with_max_segment_len(4)
+            .with_max_params(2)
+            .with_max_fragment_len(4);
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            (
+                "http://example.com/a/bc/def?x&y#frag",
+                Ok("http://example.com/a/bc/def?x&y#frag"),
+            ),
+            ("http://u:p@example.com:8080/", Err(Error::UserInfoNotSupported)),
+            ("http://[::1]:8080/", Ok("http://[::1]:8080/")),
+            ("http://example.com", Ok("http://example.com/")),
+            // The limits are checked as written, before the path is normalized.
+            ("http://example.com/a/../b/c/d", Err(TooManyPathSegments)),
+            ("http://example.com/a/b/c/", Err(TooManyPathSegments)),
+            ("http://example.com/abcde", Err(PathSegmentTooLong)),
+            ("http://example.com/%7e%7e", Err(PathSegmentTooLong)),
+            ("http://example.com/?a&b&c", Err(TooManyParams)),
+            ("http://example.com/#fragm", Err(FragmentTooLong)),
+            ("http://example.org.uk/", Err(HostTooLong)),
+            ("http://[1:2:3:4:5:6:7:8]/", Err(HostTooLong)),
+            (
+                "http://example.com/?a=aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                Err(UrlTooLong),
+            ),
+            // A limit is checked before the URL is validated.
+            ("no-scheme/abcde", Err(InvalidScheme)),
+            ("no-scheme?a&b&c", Err(TooManyParams)),
+        ];
+        let normalizer: Normalizer = Normalizer::DEFAULT.with_limits(limits);
+        for (input, expected) in test_cases {
+            let result: Result<WebUrl, Error> = normalizer.parse(input);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "input={}", input),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "input={}", input),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_limits_unlimited() {
+        let input: String = format!("http://example.com/{}", "a/".repeat(10_000));
+        assert!(Normalizer::DEFAULT.parse(&input).is_ok());
+        assert_eq!(
+            Normalizer::DEFAULT
+                .with_limits(ParseLimits::RECOMMENDED)
+                .parse(&input)
+                .unwrap_err(),
+            UrlTooLong
+        );
+    }
+}
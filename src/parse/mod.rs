@@ -1,14 +1,32 @@
+pub(crate) use diagnose::*;
 pub(crate) use finalize::*;
+pub(crate) use fixup::*;
+pub(crate) use from_str::*;
 pub(crate) use is_valid::*;
+pub(crate) use limits::*;
+pub(crate) use parse_const::*;
+pub(crate) use parse_ref::*;
 pub(crate) use parts::*;
 pub(crate) use path_plus::*;
+pub(crate) use percent::*;
 pub(crate) use pre_path::*;
+pub(crate) use reference::*;
+pub(crate) use try_from_str::*;
+pub(crate) use whatwg::*;
 
+mod diagnose;
 mod finalize;
+mod fixup;
 mod is_valid;
+mod limits;
 mod parts;
 mod path_plus;
+mod percent;
 mod pre_path;
+mod reference;
+mod whatwg;
 
 mod from_str;
+mod parse_const;
+mod parse_ref;
 mod try_from_str;
@@ -0,0 +1,717 @@
+use crate::Error::{
+    InvalidFragment, InvalidHost, InvalidPath, InvalidQuery, InvalidScheme, NotNormalized, UrlTooLong,
+    UserInfoNotSupported,
+};
+use crate::parse::{Offsets, dot_segment_dots, host_len, is_authority_end, is_percent_normalized, parse_port_digits};
+use crate::{Error, Fragment, Path, Query, Scheme, WebUrl};
+use address::{IPAddress, IPv4Address, IPv6Address};
+
+/// Parses the offsets of the web-based URL `s` in a const context. The `s` must already be normalized with
+/// [`Normalizer::DEFAULT`](crate::Normalizer::DEFAULT).
+///
+/// This gives the same result as [`parse_ref`](crate::parse::parse_ref) with the default normalizer, including the
+/// error for an invalid URL, except for a host that needs the IDNA processing, which is not const:
+/// - A Unicode host is an `InvalidHost` here. The runtime parser converts it, so it is a `NotNormalized`, or the error
+///   of a later part that is invalid. (`https://ü` & `http://ü#]A[`)
+/// - An `xn--` label is not decoded here, so an invalid A-label is accepted. The runtime parser rejects it with the
+///   `idna` feature. (`http://xn--a.example/`)
+///
+/// The scheme, port, path, query, & fragment are checked with the same const fns as the runtime parser. The `address`
+/// validation is not const, so the IP address & domain hosts are validated here with the same rules.
+pub const fn parse_const(s: &str) -> Result<Offsets, Error> {
+    let bytes: &[u8] = s.as_bytes();
+
+    // The normalization is only checked once the URL is fully validated, so an invalid URL reports why it is invalid.
+    let mut normalized: bool = true;
+
+    // The scheme must be followed by the "://".
+    let scheme_len: usize = find(bytes, 0, bytes.len(), b':');
+    let has_slashes: bool =
+        scheme_len + 3 <= bytes.len() && bytes[scheme_len + 1] == b'/' && bytes[scheme_len + 2] == b'/';
+    if scheme_len == bytes.len() || !Scheme::is_valid_ignore_case(substr(s, 0, scheme_len)) || !has_slashes {
+        return Err(InvalidScheme);
+    }
+    let scheme: &str = substr(s, 0, scheme_len);
+    normalized &= Scheme::is_valid(scheme);
+
+    // The host ends at the last ':' of the authority unless it is a bracketed IPv6 address. (see `parse_host`)
+    let host_start: usize = scheme_len + 3;
+    let authority_end: usize = authority_end(bytes, host_start);
+    if find(bytes, host_start, authority_end, b'@') != authority_end {
+        return Err(UserInfoNotSupported);
+    }
+    let host_end: usize = host_start + host_len(sub(bytes, host_start, authority_end));
+    let ip: Option<IPAddress> = match parse_host(sub(bytes, host_start, host_end)) {
+        Ok((ip, canonical)) => {
+            normalized &= canonical;
+            ip
+        }
+        Err(error) => return Err(error),
+    };
+
+    // An empty port, a port with leading zeros, & the default port of the scheme are all rewritten.
+    let mut port: Option<u16> = None;
+    if host_end != authority_end {
+        let digits: &[u8] = sub(bytes, host_end + 1, authority_end);
+        port = match parse_port_digits(digits) {
+            Ok(port) => port,
+            Err(error) => return Err(error),
+        };
+        normalized &= match port {
+            Some(port) => {
+                (digits[0] != b'0' || digits.len() == 1)
+                    && !matches!(Scheme::default_port_ignore_case(scheme), Some(default) if default == port)
+            }
+            None => false,
+        };
+    }
+
+    // The path is implied when the authority is not followed by a '/', which needs the '/' inserted.
+    let port_end: usize = authority_end;
+    let query_start: usize = find(bytes, port_end, bytes.len(), b'#');
+    let path_end: usize = if port_end < bytes.len() && bytes[port_end] == b'/' {
+        let path_end: usize = find(bytes, port_end, query_start, b'?');
+        if !Path::is_valid(substr(s, port_end, path_end)) {
+            return Err(InvalidPath);
+        }
+        path_end
+    } else {
+        normalized = false;
+        port_end
+    };
+    let query_end: usize = find(bytes, path_end, bytes.len(), b'#');
+    if query_end != path_end && !Query::is_valid(substr(s, path_end, query_end)) {
+        return Err(InvalidQuery);
+    } else if query_end != bytes.len() && !Fragment::is_valid(substr(s, query_end, bytes.len())) {
+        return Err(InvalidFragment);
+    }
+    normalized &= !has_dot_segment(substr(s, port_end, path_end));
+    normalized &= is_percent_normalized(sub(bytes, port_end, bytes.len()));
+
+    if !normalized {
+        return Err(NotNormalized);
+    } else if bytes.len() > WebUrl::MAX_LEN {
+        return Err(UrlTooLong);
+    }
+
+    Ok(Offsets {
+        scheme_len: scheme_len as u32,
+        host_start: host_start as u32,
+        host_end: host_end as u32,
+        ip,
+        port_end: port_end as u32,
+        port,
+        path_end: path_end as u32,
+        query_end: query_end as u32,
+    })
+}
+
+// Slicing
+
+/// Gets the `start..end` range of the `bytes`. (range indexing is not const)
+const fn sub(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    let (bytes, _) = bytes.split_at(end);
+    let (_, bytes) = bytes.split_at(start);
+    bytes
+}
+
+/// Gets the `start..end` range of `s`. The range must be on char boundaries.
+const fn substr(s: &str, start: usize, end: usize) -> &str {
+    let (s, _) = s.split_at(end);
+    let (_, s) = s.split_at(start);
+    s
+}
+
+/// Finds the first index of the char `c` in the `start..end` range of the `bytes`, or `end` when it is not found.
+const fn find(bytes: &[u8], start: usize, end: usize, c: u8) -> usize {
+    let mut index: usize = start;
+    while index < end && bytes[index] != c {
+        index += 1;
+    }
+    index
+}
+
+/// Finds the end of the authority that starts at the `start` of the `bytes`. (see `is_authority_end`)
+const fn authority_end(bytes: &[u8], start: usize) -> usize {
+    let mut index: usize = start;
+    while index < bytes.len() && !is_authority_end(bytes[index]) {
+        index += 1;
+    }
+    index
+}
+
+// Host & Port
+
+/// Parses the optional IP address from the `host` & checks if the `host` is canonical & lowercase.
+///
+/// A domain is validated, ignoring case. (see `parse_ip_and_validate_domain`)
+const fn parse_host(host: &[u8]) -> Result<(Option<IPAddress>, bool), Error> {
+    if host.is_empty() {
+        Err(InvalidHost)
+    } else if host[0] == b'[' {
+        if host[host.len() - 1] != b']' {
+            return Err(InvalidHost);
+        }
+        let host: &[u8] = sub(host, 1, host.len() - 1);
+        match parse_ipv6(host) {
+            Some(segments) => {
+                let ip: IPAddress = IPv6Address::from_segments(segments).to_ip();
+                Ok((Some(ip), is_canonical_ipv6(host, segments)))
+            }
+            None => Err(InvalidHost),
+        }
+    } else if let Some((octets, end)) = read_ipv4(host, 0)
+        && end == host.len()
+    {
+        // The IPv4 address has no leading zeros, so its text is always canonical.
+        Ok((Some(IPv4Address::new(octets).to_ip()), true))
+    } else if is_valid_domain(host) {
+        Ok((None, !has_uppercase(host)))
+    } else {
+        Err(InvalidHost)
+    }
+}
+
+/// Checks if the domain `name` is valid, ignoring case. (see `Domain::is_valid_name_ignore_case`)
+const fn is_valid_domain(name: &[u8]) -> bool {
+    if name.is_empty() || name.len() > 253 {
+        return false;
+    }
+    let mut start: usize = 0;
+    while start <= name.len() {
+        let end: usize = find(name, start, name.len(), b'.');
+        let label: &[u8] = sub(name, start, end);
+        if label.is_empty() || label.len() > 63 || label[0] == b'-' || label[label.len() - 1] == b'-' {
+            return false;
+        }
+        let mut index: usize = 0;
+        while index < label.len() {
+            if !label[index].is_ascii_alphanumeric() && label[index] != b'-' {
+                return false;
+            }
+            index += 1;
+        }
+        start = end + 1;
+    }
+    true
+}
+
+/// Checks if the `bytes` have an uppercase char.
+const fn has_uppercase(bytes: &[u8]) -> bool {
+    let mut index: usize = 0;
+    while index < bytes.len() {
+        if bytes[index].is_ascii_uppercase() {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+// IP Addresses
+//
+// The `address` parsing uses the `std::net` parsers, so these mirror their grammar: an address is accepted here exactly
+// when it is accepted there.
+
+/// Reads a number of 1 to `max_digits` digits in the `radix` at the `index` of the `bytes`.
+///
+/// Returns the number & the index after it. A leading zero is only read when it is the whole number unless
+/// `allow_zero_prefix` is set.
+const fn read_number(
+    bytes: &[u8],
+    index: usize,
+    radix: u32,
+    max_digits: usize,
+    allow_zero_prefix: bool,
+) -> Option<(u32, usize)> {
+    let mut number: u32 = 0;
+    let mut end: usize = index;
+    while end < bytes.len() && end - index < max_digits {
+        match (bytes[end] as char).to_digit(radix) {
+            Some(digit) => number = number * radix + digit,
+            None => break,
+        }
+        end += 1;
+    }
+    if end == index || (!allow_zero_prefix && bytes[index] == b'0' && end - index > 1) {
+        None
+    } else {
+        Some((number, end))
+    }
+}
+
+/// Reads the IPv4 address at the `index` of the `bytes`.
+///
+/// Returns the octets & the index after the address.
+const fn read_ipv4(bytes: &[u8], index: usize) -> Option<([u8; 4], usize)> {
+    let mut octets: [u8; 4] = [0; 4];
+    let mut end: usize = index;
+    let mut octet: usize = 0;
+    while octet < 4 {
+        if octet != 0 {
+            if end >= bytes.len() || bytes[end] != b'.' {
+                return None;
+            }
+            end += 1;
+        }
+        match read_number(bytes, end, 10, 3, false) {
+            Some((number, after)) if number <= u8::MAX as u32 => {
+                octets[octet] = number as u8;
+                end = after;
+            }
+            _ => return None,
+        }
+        octet += 1;
+    }
+    Some((octets, end))
+}
+
+/// Reads up to `limit` ':' separated IPv6 groups at the `index` of the `bytes` into the `groups`.
+///
+/// The last two groups may be an embedded IPv4 address. Returns the number of groups read, whether the last two groups
+/// were an IPv4 address, & the index after the groups.
+const fn read_ipv6_groups(bytes: &[u8], index: usize, groups: &mut [u16; 8], limit: usize) -> (usize, bool, usize) {
+    let mut end: usize = index;
+    let mut group: usize = 0;
+    while group < limit {
+        // The separator is only consumed along with the group after it.
+        let start: usize = if group == 0 {
+            end
+        } else if end < bytes.len() && bytes[end] == b':' {
+            end + 1
+        } else {
+            return (group, false, end);
+        };
+        if group < limit - 1
+            && let Some((octets, after)) = read_ipv4(bytes, start)
+        {
+            groups[group] = u16::from_be_bytes([octets[0], octets[1]]);
+            groups[group + 1] = u16::from_be_bytes([octets[2], octets[3]]);
+            return (group + 2, true, after);
+        }
+        match read_number(bytes, start, 16, 4, true) {
+            Some((number, after)) => {
+                groups[group] = number as u16;
+                end = after;
+            }
+            None => return (group, false, end),
+        }
+        group += 1;
+    }
+    (limit, false, end)
+}
+
+/// Parses the IPv6 address `bytes` into its segments.
+const fn parse_ipv6(bytes: &[u8]) -> Option<[u16; 8]> {
+    let mut head: [u16; 8] = [0; 8];
+    let (head_len, head_ipv4, end) = read_ipv6_groups(bytes, 0, &mut head, 8);
+    if head_len == 8 {
+        return if end == bytes.len() { Some(head) } else { None };
+    } else if head_ipv4 || end + 2 > bytes.len() || bytes[end] != b':' || bytes[end + 1] != b':' {
+        return None;
+    }
+
+    // The "::" stands for at least one zero group, so the tail has at most 7 - `head_len` groups.
+    let mut tail: [u16; 8] = [0; 8];
+    let (tail_len, _, end) = read_ipv6_groups(bytes, end + 2, &mut tail, 8 - (head_len + 1));
+    if end != bytes.len() {
+        return None;
+    }
+    let mut index: usize = 0;
+    while index < tail_len {
+        head[8 - tail_len + index] = tail[index];
+        index += 1;
+    }
+    Some(head)
+}
+
+/// Checks if the IPv6 address `bytes` are the canonical text of the `segments`.
+///
+/// The canonical text is the `std::net` display: the IPv4-mapped addresses embed the IPv4 address, & otherwise the
+/// groups are lowercase hex without leading zeros & the first longest run of two or more zero groups is elided.
+const fn is_canonical_ipv6(bytes: &[u8], segments: [u16; 8]) -> bool {
+    let mut text: Text = Text::new();
+    if segments[0] == 0
+        && segments[1] == 0
+        && segments[2] == 0
+        && segments[3] == 0
+        && segments[4] == 0
+        && segments[5] == 0xffff
+    {
+        text.push_str(b"::ffff:");
+        text.push_decimal(segments[6] >> 8);
+        text.push(b'.');
+        text.push_decimal(segments[6] & 0xff);
+        text.push(b'.');
+        text.push_decimal(segments[7] >> 8);
+        text.push(b'.');
+        text.push_decimal(segments[7] & 0xff);
+    } else {
+        let mut zeros_start: usize = 0;
+        let mut zeros_len: usize = 0;
+        let mut index: usize = 0;
+        while index < 8 {
+            let mut end: usize = index;
+            while end < 8 && segments[end] == 0 {
+                end += 1;
+            }
+            if end - index > zeros_len {
+                zeros_start = index;
+                zeros_len = end - index;
+            }
+            index = end + 1;
+        }
+
+        let mut index: usize = 0;
+        while index < 8 {
+            if zeros_len > 1 && index == zeros_start {
+                text.push_str(b"::");
+                index += zeros_len;
+            } else {
+                if index != 0 && !(zeros_len > 1 && index == zeros_start + zeros_len) {
+                    text.push(b':');
+                }
+                text.push_hex(segments[index]);
+                index += 1;
+            }
+        }
+    }
+    text.eq(bytes)
+}
+
+/// The text of an IPv6 address, written to a stack buffer.
+struct Text {
+    buffer: [u8; 45],
+    len: usize,
+}
+
+impl Text {
+    /// Creates a new empty text.
+    const fn new() -> Self {
+        Self {
+            buffer: [0; 45],
+            len: 0,
+        }
+    }
+
+    /// Pushes the char `c`.
+    const fn push(&mut self, c: u8) {
+        self.buffer[self.len] = c;
+        self.len += 1;
+    }
+
+    /// Pushes the `chars`.
+    const fn push_str(&mut self, chars: &[u8]) {
+        let mut index: usize = 0;
+        while index < chars.len() {
+            self.push(chars[index]);
+            index += 1;
+        }
+    }
+
+    /// Pushes the lowercase hex digits of the `number` without leading zeros.
+    const fn push_hex(&mut self, number: u16) {
+        let mut shift: u32 = 12;
+        while shift > 0 && (number >> shift) == 0 {
+            shift -= 4;
+        }
+        loop {
+            self.push(b"0123456789abcdef"[((number >> shift) & 0xf) as usize]);
+            if shift == 0 {
+                break;
+            }
+            shift -= 4;
+        }
+    }
+
+    /// Pushes the decimal digits of the `number` without leading zeros.
+    const fn push_decimal(&mut self, number: u16) {
+        if number >= 100 {
+            self.push(b'0' + (number / 100) as u8);
+        }
+        if number >= 10 {
+            self.push(b'0' + (number / 10 % 10) as u8);
+        }
+        self.push(b'0' + (number % 10) as u8);
+    }
+
+    /// Checks if the text is the `bytes`.
+    const fn eq(&self, bytes: &[u8]) -> bool {
+        if self.len != bytes.len() {
+            return false;
+        }
+        let mut index: usize = 0;
+        while index < bytes.len() {
+            if self.buffer[index] != bytes[index] {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+}
+
+// Path, Query, & Fragment
+
+/// Checks if the `path` has a "." or ".." segment. (an escaped dot is caught as an escaped unreserved char)
+const fn has_dot_segment(path: &str) -> bool {
+    let bytes: &[u8] = path.as_bytes();
+    let mut start: usize = 0;
+    while start < bytes.len() {
+        // The `start` is at a '/', so the segment follows it.
+        let end: usize = find(bytes, start + 1, bytes.len(), b'/');
+        if dot_segment_dots(substr(path, start + 1, end), false) != 0 {
+            return true;
+        }
+        start = end;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "idna")]
+    use crate::Error::{InvalidFragment, InvalidHost, NotNormalized};
+    use crate::parse::{Offsets, parse_const, parse_ref};
+    use crate::{Error, Normalizer};
+
+    /// The const parser must agree with the runtime parser on every URL, including the error for an invalid URL.
+    #[test]
+    fn fn_parse_const() {
+        let test_cases: &[&str] = &[
+            // Schemes
+            "",
+            "http",
+            "http:",
+            "http:/",
+            "http://",
+            "!://host/",
+            "1http://host/",
+            "HTTP://host/",
+            "hTtp://host/",
+            "a+b-c.d://host/",
+            "http:/host/",
+            // User Info
+            "http://user@host/",
+            "http://@host/",
+            "http://host/a@b",
+            // Domains
+            "http://host/",
+            "http://HOST/",
+            "http://a.b.c/",
+            "http://a..b/",
+            "http://.a/",
+            "http://a./",
+            "http://-a/",
+            "http://a-/",
+            "http://a--b/",
+            "http://a_b/",
+            "http://xn--bcher-kva.example/",
+            "http://999.1.1.1/",
+            "http://1.2.3/",
+            "http://1.2.3.4.5/",
+            "http://ho st/",
+            // IPv4 Addresses
+            "http://127.0.0.1/",
+            "http://0.0.0.0/",
+            "http://255.255.255.255/",
+            "http://256.1.1.1/",
+            "http://127.0.0.01/",
+            "http://1234.1.1.1/",
+            // IPv6 Addresses
+            "http://[::1]/",
+            "http://[::]/",
+            "http://[0::1]/",
+            "http://[::0:1]/",
+            "http://[0:0:0:0:0:0:0:1]/",
+            "http://[1::]/",
+            "http://[1:0:0:2::]/",
+            "http://[1::2:0:0:3]/",
+            "http://[1:0:0:2:0:0:0:3]/",
+            "http://[1:0:0:2::3]/",
+            "http://[1::2:0:0:0:3]/",
+            "http://[1:0:2:0:3:0:4:0]/",
+            "http://[1:0:2:3:4:5:6:7]/",
+            "http://[1::2:3:4:5:6:7]/",
+            "http://[abcd::ef]/",
+            "http://[ABCD::EF]/",
+            "http://[0abc::1]/",
+            "http://[12345::1]/",
+            "http://[::ffff:1.2.3.4]/",
+            "http://[::ffff:102:304]/",
+            "http://[::FFFF:1.2.3.4]/",
+            "http://[::1.2.3.4]/",
+            "http://[::102:304]/",
+            "http://[1.2.3.4::]/",
+            "http://[1:2:3:4:5:6:1.2.3.4]/",
+            "http://[1:2:3:4:5:6:7:1.2.3.4]/",
+            "http://[1:2:3:4:5:6:7:8]/",
+            "http://[1:2:3:4:5:6:7:8:9]/",
+            "http://[1:2:3:4:5:6:7::]/",
+            "http://[1:2:3:4:5:6:7:8::]/",
+            "http://[::1::]/",
+            "http://[:::]/",
+            "http://[:1]/",
+            "http://[1:]/",
+            "http://[]/",
+            "http://[/",
+            "http://[::1/",
+            "http://::1/",
+            "http://[::1]80/",
+            "http://[::1%1]/",
+            // Ports
+            "http://host:8080/",
+            "http://host:80/",
+            "HTTP://host:80/",
+            "https://host:80/",
+            "https://host:443/",
+            "ftp://host:21/",
+            "http://host:/",
+            "http://host:0/",
+            "http://host:00/",
+            "http://host:08080/",
+            "http://host:65535/",
+            "http://host:65536/",
+            "http://host:+80/",
+            "http://host:8a/",
+            "http://:80/",
+            "http://[::1]:8080/",
+            // Paths
+            "http://host",
+            "http://host?q",
+            "http://host#f",
+            "http://host/a/b/",
+            "http://host//",
+            "http://host/.",
+            "http://host/..",
+            "http://host/a/./b",
+            "http://host/a/../b",
+            "http://host/.a/..b/a.",
+            "http://host/...",
+            "http://host/%2e",
+            "http://host/a b",
+            "http://host/%",
+            "http://host/%zz",
+            "http://host/\u{4f60}",
+            // Percent-Encoding
+            "http://host/%2F",
+            "http://host/%2f",
+            "http://host/%41",
+            "http://host/%7E",
+            "http://host/%5F%2D",
+            "http://host/?%2F#%3F",
+            "http://host/?%2f",
+            "http://host/#%2f",
+            "http://host/?%7e",
+            // Queries & Fragments
+            "http://host/?",
+            "http://host/#",
+            "http://host/?#",
+            "http://host/?a=1&b=2#f",
+            "http://host/??",
+            "http://host/?a b",
+            "http://host/?%",
+            "http://host/##",
+            "http://host/#a b",
+            "http://host/#?",
+            "http://host?a b",
+            "http://host#a b",
+        ];
+        for input in test_cases {
+            let result: Result<Offsets, Error> = parse_const(input);
+            let expected: Result<Offsets, Error> = parse_ref(input, Normalizer::DEFAULT);
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    /// The const parser must agree with the runtime parser on every combination of the URL parts.
+    #[test]
+    fn parse_const_differential() {
+        let schemes: &[&str] = &["http://", "HTTPS://", "x-1+.://", "1x://", "http:/", "http:"];
+        let hosts: &[&str] = &[
+            "",
+            "host",
+            "Host",
+            "a.b-c.d",
+            "-a",
+            "a-",
+            "a..b",
+            "a.",
+            "u@h",
+            "h_t",
+            "a%41",
+            "127.0.0.1",
+            "127.0.0.01",
+            "1.2.3",
+            "256.0.0.1",
+            "[::1]",
+            "[::FFFF:1.2.3.4]",
+            "[::ffff:1.2.3.4]",
+            "[0:0::1]",
+            "[1:2:3:4:5:6:7:8]",
+            "[1::2::3]",
+            "[::1",
+            "::1]",
+        ];
+        let ports: &[&str] = &["", ":", ":80", ":443", ":0080", ":8080", ":65536", ":+1", ":a"];
+        let paths: &[&str] = &[
+            "", "/", "/a/b", "/./a", "/a/..", "/%2e", "/%7e", "/%7E", "/%2f", "/%2F", "/a b", "/%",
+        ];
+        let rests: &[&str] = &["", "?", "?q=%41", "?%2f", "#", "#f", "#%zz", "?a#b"];
+        for scheme in schemes {
+            for host in hosts {
+                for port in ports {
+                    for path in paths {
+                        for rest in rests {
+                            let input: String = format!("{}{}{}{}{}", scheme, host, port, path, rest);
+                            let result: Result<Offsets, Error> = parse_const(&input);
+                            let expected: Result<Offsets, Error> = parse_ref(&input, Normalizer::DEFAULT);
+                            assert_eq!(result, expected, "input={}", input);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// The const IP address parsers must agree with the `address` parsers on every short host of the IP address chars.
+    #[test]
+    fn parse_const_differential_ip() {
+        let test_cases: &[(&[u8], usize, &str)] = &[(b"0:1.fF", 6, "http://[{}]/"), (b"0.12", 8, "http://{}/")];
+        for (chars, max_len, format) in test_cases {
+            let mut hosts: Vec<String> = vec![String::new()];
+            for _ in 0..*max_len {
+                hosts = hosts
+                    .iter()
+                    .flat_map(|host| chars.iter().map(move |c| format!("{}{}", host, *c as char)))
+                    .collect();
+                for host in &hosts {
+                    let input: String = format.replace("{}", host);
+                    let result: Result<Offsets, Error> = parse_const(&input);
+                    let expected: Result<Offsets, Error> = parse_ref(&input, Normalizer::DEFAULT);
+                    assert_eq!(result, expected, "input={}", input);
+                }
+            }
+        }
+    }
+
+    /// The const parser differs from the runtime parser only on the hosts that need the IDNA processing.
+    #[test]
+    #[cfg(feature = "idna")]
+    fn fn_parse_const_idna() {
+        type Expected = (Result<(), Error>, Result<(), Error>);
+        let test_cases: &[(&str, Expected)] = &[
+            ("https://\u{fc}", (Err(InvalidHost), Err(NotNormalized))),
+            ("http://\u{fc}#]A[", (Err(InvalidHost), Err(InvalidFragment))),
+            ("http://\u{5d0}a.de/", (Err(InvalidHost), Err(InvalidHost))),
+            ("http://xn--a.example/", (Ok(()), Err(InvalidHost))),
+            ("http://xn--bcher-kva.example/", (Ok(()), Ok(()))),
+        ];
+        for (input, (expected_const, expected_ref)) in test_cases {
+            let result: Result<(), Error> = parse_const(input).map(|_| ());
+            assert_eq!(result, *expected_const, "input={}", input);
+            let result: Result<(), Error> = parse_ref(input, Normalizer::DEFAULT).map(|_| ());
+            assert_eq!(result, *expected_ref, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::Error::{NotNormalized, UrlTooLong};
+use crate::parse::{Parts, PrePath, parse_parts};
+use crate::{Error, Normalizer, WebUrl};
+use address::IPAddress;
+
+/// The offsets of a web-based URL string that is already normalized. (see [`WebUrl::new_unchecked_with_host_start`])
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Offsets {
+    pub scheme_len: u32,
+    pub host_start: u32,
+    pub host_end: u32,
+    pub ip: Option<IPAddress>,
+    pub port_end: u32,
+    pub port: Option<u16>,
+    pub path_end: u32,
+    pub query_end: u32,
+}
+
+/// Parses the offsets of the web-based URL `s` so it can be borrowed. The `s` must already be normalized with the
+/// `normalizer`.
+pub fn parse_ref(s: &str, normalizer: Normalizer) -> Result<Offsets, Error> {
+    let parts: Parts = parse_parts(s, normalizer)?;
+
+    // A URL that needs any rewrite, even one done in place, would need a new string.
+    if !parts.is_normalized() || !parts.is_case_normalized(s) {
+        return Err(NotNormalized);
+    }
+    if s.len() > WebUrl::MAX_LEN {
+        return Err(UrlTooLong);
+    }
+
+    // The URL is normalized, so the parsed lengths are the canonical lengths.
+    let pre_path: PrePath = parts.pre_path;
+    let port_end: usize = pre_path.len();
+    let path_end: usize = port_end + parts.path_plus.path_len;
+    let query_end: usize = path_end + parts.path_plus.query_len;
+
+    Ok(Offsets {
+        scheme_len: pre_path.scheme_len as u32,
+        host_start: pre_path.host_start() as u32,
+        host_end: pre_path.host_end() as u32,
+        ip: pre_path.ip,
+        port_end: port_end as u32,
+        port: pre_path.port,
+        path_end: path_end as u32,
+        query_end: query_end as u32,
+    })
+}
@@ -1,11 +1,12 @@
-use crate::Error;
 use crate::parse::{
-    CanonicalHost, CanonicalPort, PathPlus, PrePath, parse_path_plus, parse_pre_path, parse_query_plus,
-    write_canonical_path,
+    CanonicalHost, CanonicalPort, PathPlus, PrePath, check_limits, is_percent_uppercase, parse_path_plus,
+    parse_pre_path, parse_query_plus, write_canonical_fragment, write_canonical_path, write_canonical_query,
 };
+use crate::{Error, Normalizer};
+use address::Domain;
 
 /// The validated parts of a web-based URL.
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub struct Parts {
     pub pre_path: PrePath,
     pub path_plus: PathPlus,
@@ -13,8 +14,15 @@ pub struct Parts {
     /// Set when the URL has no explicit path & a '/' must be inserted after the authority.
     pub needs_slash: bool,
 
-    /// Set when the host is an IP address that must be rewritten in its canonical form.
+    /// Set when the host is an IP address that must be rewritten in its canonical form, is a Unicode domain that must
+    /// be rewritten in its A-label form, or has a stripped trailing dot.
     pub needs_host_rewrite: bool,
+
+    /// The A-label form of the host when it is a Unicode domain, as converted when parsing.
+    pub idna_host: Option<Domain>,
+
+    /// The normalizer the parts were parsed with, which gives the canonical lengths.
+    pub normalizer: Normalizer,
 }
 
 impl Parts {
@@ -22,75 +30,101 @@ impl Parts {
 
     /// Checks if the port must be rewritten to normalize the URL.
     ///
-    /// This is set when the parsed port was empty or had leading zeros.
-    pub const fn needs_port_rewrite(self) -> bool {
+    /// This is set when the parsed port was empty, had leading zeros, or was the default port of the scheme.
+    pub const fn needs_port_rewrite(&self) -> bool {
         self.pre_path.port_len != self.pre_path.canonical_port_len()
     }
 
     /// Checks if the path must be rewritten to normalize the URL.
     ///
-    /// This is set when the parsed path has dot-segments, which always shorten it.
-    pub const fn needs_path_rewrite(self) -> bool {
+    /// This is set when the parsed path has dot-segments or escaped unreserved chars, which always shorten it.
+    pub const fn needs_path_rewrite(&self) -> bool {
         self.path_plus.path_len != self.path_plus.canonical_path_len
     }
 
-    /// Checks if the host, port, or path must be rewritten to normalize the URL.
+    /// Checks if the query or fragment must be rewritten to normalize the URL.
+    ///
+    /// This is set when the parsed query or fragment has escaped unreserved chars, which always shorten it.
+    pub const fn needs_query_plus_rewrite(&self) -> bool {
+        self.path_plus.query_len != self.path_plus.canonical_query_len
+            || self.path_plus.fragment_len != self.path_plus.canonical_fragment_len
+    }
+
+    /// Checks if the host, port, path, query, or fragment must be rewritten to normalize the URL.
     ///
-    /// A rewrite changes the length of the URL before the query, so it cannot be normalized in place.
-    pub const fn needs_rewrite(self) -> bool {
-        self.needs_host_rewrite || self.needs_port_rewrite() || self.needs_path_rewrite()
+    /// A rewrite changes the length of the URL, so it cannot be normalized in place.
+    pub const fn needs_rewrite(&self) -> bool {
+        self.needs_host_rewrite
+            || self.needs_port_rewrite()
+            || self.needs_path_rewrite()
+            || self.needs_query_plus_rewrite()
     }
 
     /// Checks if the parsed URL string is already normalized, ignoring the letter case.
     ///
-    /// The letter case is excluded since it is normalized in place & never changes the length.
-    pub const fn is_normalized(self) -> bool {
+    /// The letter case is excluded since it is normalized in place & never changes the length. This includes the case
+    /// of the percent-encoded hex digits.
+    pub const fn is_normalized(&self) -> bool {
         !self.needs_slash && !self.needs_rewrite()
     }
 
-    /// Gets the length of the normalized URL string for a parsed URL of `len` chars.
-    pub fn normalized_len(self, len: usize) -> usize {
-        // The canonical path is never longer than the parsed path since it only drops dot-segments.
-        let dropped: usize = self.path_plus.path_len - self.path_plus.canonical_path_len;
+    /// Checks if the letter case of the parsed URL `s` is normalized.
+    ///
+    /// The scheme & host must be lowercase, & the percent-encoded hex digits must be uppercase when the
+    /// percent-encoding is normalized.
+    pub fn is_case_normalized(&self, s: &str) -> bool {
+        self.pre_path.is_lowercase(s)
+            && (!self.normalizer.normalize_percent() || is_percent_uppercase(&s[self.pre_path.len()..]))
+    }
 
-        ((len - self.pre_path.len()) - dropped) + self.pre_path.canonical_len() + (self.needs_slash as usize)
+    /// Gets the length of the normalized URL string.
+    pub fn normalized_len(&self) -> usize {
+        // The implied '/' path is counted in the canonical path length, so a missing '/' needs no extra room.
+        self.pre_path.canonical_len()
+            + self.path_plus.canonical_path_len
+            + self.path_plus.canonical_query_len
+            + self.path_plus.canonical_fragment_len
     }
 
     /// Gets the index the '/' must be inserted at. (only meaningful when `needs_slash` is set)
     ///
     /// This is an index into the parsed URL, so it is only valid when neither the host nor the port is rewritten.
-    pub const fn slash_index(self) -> usize {
+    pub const fn slash_index(&self) -> usize {
         self.pre_path.len()
     }
 }
 
 /// Writes the normalized URL for the parsed URL `s` to `url`.
 ///
-/// The `parts` must have been parsed from `s`. The letter case is **not** normalized here; that is done in place once
-/// the URL string is built.
-pub fn write_normalized(s: &str, parts: Parts, url: &mut String) {
+/// The `parts` must have been parsed from `s`. The letter case of the scheme & host is **not** normalized here; that is
+/// done in place once the URL string is built.
+pub fn write_normalized(s: &str, parts: &Parts, url: &mut String) {
     let pre_path: PrePath = parts.pre_path;
 
     url.push_str(&s[..pre_path.host_start()]);
     if let Some(ip) = pre_path.ip {
         url.push_str(CanonicalHost::new(ip).as_str());
+    } else if let Some(domain) = &parts.idna_host {
+        url.push_str(domain.name());
     } else {
-        url.push_str(pre_path.host_str(s));
+        // The canonical host length excludes a stripped trailing dot.
+        url.push_str(&pre_path.host_str(s)[..pre_path.canonical_host_len()]);
     }
     if let Some(port) = pre_path.port {
         url.push_str(CanonicalPort::new(port).as_str());
     }
 
     let after_authority: &str = &s[pre_path.len()..];
-    if parts.needs_slash {
+    let (path, query_plus) = if parts.needs_slash {
         // The URL has no explicit path, so the implied '/' precedes the query & fragment.
-        url.push('/');
-        url.push_str(after_authority);
+        ("/", after_authority)
     } else {
-        let (path, path_plus) = after_authority.split_at(parts.path_plus.path_len);
-        write_canonical_path(path, url);
-        url.push_str(path_plus);
-    }
+        after_authority.split_at(parts.path_plus.path_len)
+    };
+    let (query, fragment) = query_plus.split_at(parts.path_plus.query_len);
+    write_canonical_path(path, parts.normalizer, url);
+    write_canonical_query(query, parts.normalizer, url);
+    write_canonical_fragment(fragment, parts.normalizer, url);
 }
 
 /// Parses & validates the web-based URL `s` without allocating.
@@ -98,8 +132,13 @@ pub fn write_normalized(s: &str, parts: Parts, url: &mut String) {
 /// The URL is **not** required to have an explicit path. When it does not, `needs_slash` will be set on the returned
 /// parts & the caller is responsible for inserting the '/' at
 /// `slash_index()` when it builds the normalized URL string.
-pub fn parse_parts(s: &str) -> Result<Parts, Error> {
-    let pre_path: PrePath = parse_pre_path(s)?;
+///
+/// The canonical lengths are as normalized by the `normalizer` & the user info is only accepted when it accepts it.
+/// The parse limits of the `normalizer` are checked first.
+pub fn parse_parts(s: &str, normalizer: Normalizer) -> Result<Parts, Error> {
+    check_limits(s, normalizer.limits()).map_err(|(error, _)| error)?;
+
+    let (pre_path, idna_host) = parse_pre_path(s, normalizer)?;
     let needs_host_rewrite: bool = pre_path.needs_host_rewrite(s);
 
     // The authority is terminated by a '/', '?', or '#' char, or by the end of the URL. Only the '/' case has an
@@ -107,9 +146,9 @@ pub fn parse_parts(s: &str) -> Result<Parts, Error> {
     let after_authority: &str = &s[pre_path.len()..];
     let needs_slash: bool = !after_authority.starts_with('/');
     let path_plus: PathPlus = if needs_slash {
-        parse_query_plus(after_authority)?
+        parse_query_plus(after_authority, normalizer)?
     } else {
-        parse_path_plus(after_authority)?
+        parse_path_plus(after_authority, normalizer)?
     };
 
     Ok(Parts {
@@ -117,20 +156,22 @@ pub fn parse_parts(s: &str) -> Result<Parts, Error> {
         path_plus,
         needs_slash,
         needs_host_rewrite,
+        idna_host,
+        normalizer,
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Error;
     use crate::Error::{InvalidHost, InvalidPath, InvalidQuery, InvalidScheme};
     use crate::parse::{Parts, parse_parts, write_normalized};
+    use crate::{Error, Normalizer};
 
     /// The summary of the parsed parts. `(needs_slash, slash_index, path_len, query_len)`
     type Summary = (bool, usize, usize, usize);
 
     fn parts_of(s: &str) -> Result<Summary, Error> {
-        parse_parts(s).map(|p: Parts| {
+        parse_parts(s, Normalizer::DEFAULT).map(|p: Parts| {
             (
                 p.needs_slash,
                 p.slash_index(),
@@ -171,24 +212,34 @@ mod tests {
             ("http://host/p?q#f", "http://host/p?q#f"),
             ("http://host", "http://host/"),
             ("http://host?q#f", "http://host/?q#f"),
-            ("http://host:0080/p", "http://host:80/p"),
+            ("http://host:0080/p", "http://host/p"),
+            ("http://host:08080/p", "http://host:8080/p"),
             ("http://host:/p?q", "http://host/p?q"),
-            ("http://[0:0:0:0:0:0:0:1]:0080/a/../b?q#f", "http://[::1]:80/b?q#f"),
+            ("http://[0:0:0:0:0:0:0:1]:0443/a/../b?q#f", "http://[::1]:443/b?q#f"),
             // The letter case is normalized in place once the URL string is built, not here.
             ("HTTP://HOST/P", "HTTP://HOST/P"),
+            // The percent-encoding is normalized in every component but the user info.
+            (
+                "http://host/%7e/%2e%2e/a%2fb?%7e%2f#%7e%2f",
+                "http://host/a%2Fb?~%2F#~%2F",
+            ),
+            ("http://host?%41#%42", "http://host/?A#B"),
+            // The user info is kept as it is.
+            ("http://User:Pass@[0:0:0:0:0:0:0:1]:0443", "http://User:Pass@[::1]:443/"),
         ];
         for (input, expected) in test_cases {
-            let parts: Parts = parse_parts(input).unwrap();
+            let parts: Parts = parse_parts(input, Normalizer::DEFAULT.with_user_info(true)).unwrap();
 
             let mut result: String = String::new();
-            write_normalized(input, parts, &mut result);
+            write_normalized(input, &parts, &mut result);
             assert_eq!(result, *expected, "input={}", input);
 
             // The length must match what is written exactly; it sizes the URL allocation.
-            assert_eq!(parts.normalized_len(input.len()), result.len(), "input={}", input);
+            assert_eq!(parts.normalized_len(), result.len(), "input={}", input);
 
-            // A URL written back unchanged is exactly the one that needs no rewrite.
-            assert_eq!(parts.is_normalized(), *input == result, "input={}", input);
+            // A URL written back unchanged, apart from the letter case, is exactly the one that needs no rewrite.
+            let unchanged: bool = input.len() == result.len() && input.eq_ignore_ascii_case(result.as_str());
+            assert_eq!(parts.is_normalized(), unchanged, "input={}", input);
         }
     }
 }
@@ -1,70 +1,115 @@
-/// Gets the length of the `path` with the dot-segments removed.
+use crate::Normalizer;
+use crate::parse::{dot_segment_dots, percent_normalized_len, write_percent_normalized};
+use std::iter::Peekable;
+use std::str::Split;
+
+/// Gets the length of the `path` as normalized by the `normalizer`.
 ///
 /// The `path` must be valid, so it starts with a '/'.
 ///
 /// # RFC 3986
+/// The percent-encoding is normalized first, so an escaped dot-segment like `%2e%2e` is removed too.
 /// <https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4>
-pub fn canonical_path_len(path: &str) -> usize {
+pub fn canonical_path_len(path: &str, normalizer: Normalizer) -> usize {
     // The segments are scanned in reverse so the segments a ".." removes are known without a stack: a ".." raises the
-    // skip count & the next kept segment lowers it.
+    // skip count & the next kept segment lowers it. The last segment is scanned first.
     let mut len: usize = 0;
     let mut skip: usize = 0;
-    for segment in path[1..].rsplit('/') {
-        match segment {
-            "." => {}
-            ".." => skip += 1,
-            segment => {
+    for (index, segment) in path[1..].rsplit('/').enumerate() {
+        if is_collapsed(segment, index == 0, normalizer) {
+            continue;
+        }
+        match removed_dots(segment, normalizer) {
+            1 => {}
+            2 => skip += 1,
+            _ => {
                 if skip != 0 {
                     skip -= 1;
                 } else {
-                    len += 1 + segment.len();
+                    len += 1 + segment_len(segment, normalizer);
                 }
             }
         }
     }
 
-    len + (ends_with_dot_segment(path) as usize)
+    len + (ends_with_dot_segment(path, normalizer) as usize)
 }
 
-/// Writes the `path` to `url` with the dot-segments removed.
+/// Writes the `path` to `url` as normalized by the `normalizer`.
 ///
-/// The `path` must be valid, so it starts with a '/'.
+/// The `path` must start with a '/'. It need not be otherwise valid, since a '%' char that does not begin an escape is
+/// written as it is.
 ///
 /// # RFC 3986
 /// <https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4>
-pub fn write_canonical_path(path: &str, url: &mut String) {
+pub fn write_canonical_path(path: &str, normalizer: Normalizer, url: &mut String) {
     // The segments are written as they are scanned & a ".." truncates the last written segment, so the written path is
     // the segment stack. The truncation never reaches past `start`, which is what keeps a leading ".." from escaping
     // the path.
     let start: usize = url.len();
-    for segment in path[1..].split('/') {
-        match segment {
-            "." => {}
-            ".." => {
+    let mut segments: Peekable<Split<char>> = path[1..].split('/').peekable();
+    while let Some(segment) = segments.next() {
+        if is_collapsed(segment, segments.peek().is_none(), normalizer) {
+            continue;
+        }
+        match removed_dots(segment, normalizer) {
+            1 => {}
+            2 => {
                 if let Some(index) = url[start..].rfind('/') {
                     url.truncate(start + index);
                 }
             }
-            segment => {
+            _ => {
                 url.push('/');
-                url.push_str(segment);
+                if normalizer.normalize_percent() {
+                    write_percent_normalized(segment, url);
+                } else {
+                    url.push_str(segment);
+                }
             }
         }
     }
 
-    if ends_with_dot_segment(path) {
+    if ends_with_dot_segment(path, normalizer) {
         url.push('/');
     }
 }
 
-/// Checks if the last segment of the `path` is a "." or ".." segment, which leaves the canonical path ending with a
-/// '/'.
-fn ends_with_dot_segment(path: &str) -> bool {
-    path.ends_with("/.") || path.ends_with("/..")
+/// Checks if the `segment` is an empty segment that the `normalizer` collapses.
+///
+/// The `last` segment is never collapsed since it holds the trailing '/' of the path.
+fn is_collapsed(segment: &str, last: bool, normalizer: Normalizer) -> bool {
+    normalizer.collapse_slashes() && segment.is_empty() && !last
+}
+
+/// Counts the dots of the `segment` when it is a dot-segment that the `normalizer` removes.
+fn removed_dots(segment: &str, normalizer: Normalizer) -> usize {
+    if normalizer.remove_dot_segments() {
+        dot_segment_dots(segment, normalizer.normalize_percent())
+    } else {
+        0
+    }
+}
+
+/// Gets the length of the `segment` as written by the `normalizer`.
+fn segment_len(segment: &str, normalizer: Normalizer) -> usize {
+    if normalizer.normalize_percent() {
+        percent_normalized_len(segment)
+    } else {
+        segment.len()
+    }
+}
+
+/// Checks if the last segment of the `path` is a "." or ".." segment that the `normalizer` removes, which leaves the
+/// canonical path ending with a '/'.
+fn ends_with_dot_segment(path: &str, normalizer: Normalizer) -> bool {
+    let last: &str = &path[path.rfind('/').map(|index| index + 1).unwrap_or(0)..];
+    removed_dots(last, normalizer) != 0
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::Normalizer;
     use crate::parse::{canonical_path_len, write_canonical_path};
 
     #[test]
@@ -98,21 +143,32 @@ mod tests {
             ("/..a", "/..a"),
             ("/a..", "/a.."),
             ("/.a/..", "/"),
-            // The percent-encoding is not decoded, so an escaped dot-segment is ordinary text.
-            ("/%2e%2e", "/%2e%2e"),
-            ("/a/%2e%2e", "/a/%2e%2e"),
+            // The percent-encoding is normalized first, so an escaped dot-segment is a dot-segment.
+            ("/%2e%2e", "/"),
+            ("/a/%2e%2e", "/"),
+            ("/a/%2E", "/a/"),
+            ("/a/.%2e/b", "/b"),
+            ("/a/%2e%2e%2e", "/a/..."),
+            // The escaped unreserved chars are decoded & the other hex digits are uppercased.
+            ("/%7euser/%41", "/~user/A"),
+            ("/a%2fb/%c3%a9", "/a%2Fb/%C3%A9"),
         ];
         for (path, expected) in test_cases {
             let mut result: String = String::new();
-            write_canonical_path(path, &mut result);
+            write_canonical_path(path, Normalizer::DEFAULT, &mut result);
             assert_eq!(result, *expected, "path={}", path);
 
             // The length must match what is written exactly; it sizes the URL allocation.
-            assert_eq!(canonical_path_len(path), result.len(), "path={}", path);
+            assert_eq!(
+                canonical_path_len(path, Normalizer::DEFAULT),
+                result.len(),
+                "path={}",
+                path
+            );
 
             // The canonical path is the canonical form, so canonicalizing it again must not change it.
             let mut again: String = String::new();
-            write_canonical_path(result.as_str(), &mut again);
+            write_canonical_path(result.as_str(), Normalizer::DEFAULT, &mut again);
             assert_eq!(again, result, "path={}", path);
         }
     }
@@ -121,7 +177,38 @@ mod tests {
     #[test]
     fn fn_write_canonical_path_appends() {
         let mut result: String = String::from("https://host");
-        write_canonical_path("/a/../../b", &mut result);
+        write_canonical_path("/a/../../b", Normalizer::DEFAULT, &mut result);
         assert_eq!(result, "https://host/b");
     }
+
+    #[test]
+    fn fn_write_canonical_path_normalizer() {
+        let collapse: Normalizer = Normalizer::DEFAULT.with_collapse_slashes(true);
+        let keep_dots: Normalizer = Normalizer::DEFAULT.with_remove_dot_segments(false);
+        let keep_percent: Normalizer = Normalizer::DEFAULT.with_normalize_percent(false);
+        let test_cases: &[(&str, Normalizer, &str)] = &[
+            // The minimal profile writes the path as it is.
+            ("/a/./b/../%7e%2f//", Normalizer::MINIMAL, "/a/./b/../%7e%2f//"),
+            // The empty segments are collapsed except for the last, which holds the trailing '/'.
+            ("//", collapse, "/"),
+            ("///a", collapse, "/a"),
+            ("/a//b", collapse, "/a/b"),
+            ("/a//", collapse, "/a/"),
+            ("/a//.", collapse, "/a/"),
+            // The empty segments are collapsed before the dot-segments are removed.
+            ("/a//../b", Normalizer::DEFAULT, "/a/b"),
+            ("/a//../b", collapse, "/b"),
+            // The dot-segments can be kept while the percent-encoding is normalized.
+            ("/a/%2e%2e/%7e", keep_dots, "/a/../~"),
+            // An escaped dot-segment is only a dot-segment when the percent-encoding is normalized.
+            ("/a/%2e%2e/b/../c", keep_percent, "/a/%2e%2e/c"),
+            ("/a/%2e", keep_percent, "/a/%2e"),
+        ];
+        for (path, normalizer, expected) in test_cases {
+            let mut result: String = String::new();
+            write_canonical_path(path, *normalizer, &mut result);
+            assert_eq!(result, *expected, "path={}", path);
+            assert_eq!(canonical_path_len(path, *normalizer), result.len(), "path={}", path);
+        }
+    }
 }
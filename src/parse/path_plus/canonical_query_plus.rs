@@ -0,0 +1,90 @@
+use crate::Normalizer;
+use crate::parse::{percent_normalized_len, write_percent_normalized};
+
+/// Gets the length of the `query` as normalized by the `normalizer`.
+///
+/// The `query` must be valid or empty, so it starts with a '?' when it is not empty.
+pub fn canonical_query_len(query: &str, normalizer: Normalizer) -> usize {
+    canonical_len(query, normalizer.remove_empty_query(), normalizer)
+}
+
+/// Writes the `query` to `url` as normalized by the `normalizer`.
+pub fn write_canonical_query(query: &str, normalizer: Normalizer, url: &mut String) {
+    write_canonical(query, normalizer.remove_empty_query(), normalizer, url);
+}
+
+/// Gets the length of the `fragment` as normalized by the `normalizer`.
+///
+/// The `fragment` must be valid or empty, so it starts with a '#' when it is not empty.
+pub fn canonical_fragment_len(fragment: &str, normalizer: Normalizer) -> usize {
+    canonical_len(fragment, normalizer.remove_empty_fragment(), normalizer)
+}
+
+/// Writes the `fragment` to `url` as normalized by the `normalizer`.
+pub fn write_canonical_fragment(fragment: &str, normalizer: Normalizer, url: &mut String) {
+    write_canonical(fragment, normalizer.remove_empty_fragment(), normalizer, url);
+}
+
+/// Gets the length of the query or fragment `s` as normalized by the `normalizer`.
+///
+/// The `s` is dropped when it is just its '?' or '#' prefix & `remove_empty` is set.
+fn canonical_len(s: &str, remove_empty: bool, normalizer: Normalizer) -> usize {
+    if remove_empty && s.len() == 1 {
+        0
+    } else if normalizer.normalize_percent() {
+        percent_normalized_len(s)
+    } else {
+        s.len()
+    }
+}
+
+/// Writes the query or fragment `s` to `url` as normalized by the `normalizer`. (see [`canonical_len`])
+fn write_canonical(s: &str, remove_empty: bool, normalizer: Normalizer, url: &mut String) {
+    if remove_empty && s.len() == 1 {
+        // The empty query or fragment is dropped along with its prefix.
+    } else if normalizer.normalize_percent() {
+        write_percent_normalized(s, url);
+    } else {
+        url.push_str(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Normalizer;
+    use crate::parse::{canonical_fragment_len, canonical_query_len, write_canonical_fragment, write_canonical_query};
+
+    #[test]
+    fn fn_write_canonical_query_plus() {
+        let remove_empty: Normalizer = Normalizer::DEFAULT
+            .with_remove_empty_query(true)
+            .with_remove_empty_fragment(true);
+        let test_cases: &[(&str, Normalizer, &str)] = &[
+            ("", Normalizer::DEFAULT, ""),
+            ("?", Normalizer::DEFAULT, "?"),
+            ("?%7e%2f", Normalizer::DEFAULT, "?~%2F"),
+            ("?%7e%2f", Normalizer::MINIMAL, "?%7e%2f"),
+            ("?", remove_empty, ""),
+            ("?&", remove_empty, "?&"),
+            ("?%7e", remove_empty, "?~"),
+        ];
+        for (query, normalizer, expected) in test_cases {
+            let mut result: String = String::new();
+            write_canonical_query(query, *normalizer, &mut result);
+            assert_eq!(result, *expected, "query={}", query);
+            assert_eq!(canonical_query_len(query, *normalizer), result.len(), "query={}", query);
+
+            // The fragment is normalized like the query.
+            let fragment: String = query.replace('?', "#");
+            let mut result: String = String::new();
+            write_canonical_fragment(fragment.as_str(), *normalizer, &mut result);
+            assert_eq!(result, expected.replace('?', "#"), "fragment={}", fragment);
+            assert_eq!(
+                canonical_fragment_len(fragment.as_str(), *normalizer),
+                result.len(),
+                "fragment={}",
+                fragment
+            );
+        }
+    }
+}
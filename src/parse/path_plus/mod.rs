@@ -1,10 +1,12 @@
 pub(crate) use canonical_path::*;
+pub(crate) use canonical_query_plus::*;
 pub(crate) use fragment::*;
 pub(crate) use path::*;
 pub(crate) use path_plus::*;
 pub(crate) use query::*;
 
 mod canonical_path;
+mod canonical_query_plus;
 mod fragment;
 mod path;
 mod path_plus;
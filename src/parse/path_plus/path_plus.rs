@@ -1,29 +1,37 @@
-use crate::Error;
-use crate::parse::{canonical_path_len, check_fragment, parse_path, parse_query};
+use crate::parse::{
+    canonical_fragment_len, canonical_path_len, canonical_query_len, check_fragment, parse_path, parse_query,
+};
+use crate::{Error, Normalizer};
 
 /// The parsing data for a web-based URL from the path to the end.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub struct PathPlus {
-    pub path_len: usize,           // length of the path including the '/' (will be 1+)
-    pub canonical_path_len: usize, // length of the path without the dot-segments (will be 1+)
-    pub query_len: usize,          // length of query including the '?' (will be 0+)
+    pub path_len: usize,               // length of the path including the '/' (will be 1+)
+    pub canonical_path_len: usize,     // length of the normalized path (will be 1+)
+    pub query_len: usize,              // length of the query including the '?' (will be 0+)
+    pub canonical_query_len: usize,    // length of the normalized query (will be 0+)
+    pub fragment_len: usize,           // length of the fragment including the '#' (will be 0+)
+    pub canonical_fragment_len: usize, // length of the normalized fragment (will be 0+)
 }
 
 /// Parses the `path_plus`. (the path, the optional query, & the optional fragment)
 ///
-/// The path, query, & fragment will be validated.
-pub fn parse_path_plus(path_plus: &str) -> Result<PathPlus, Error> {
+/// The path, query, & fragment will be validated. The canonical lengths are as normalized by the `normalizer`.
+pub fn parse_path_plus(path_plus: &str, normalizer: Normalizer) -> Result<PathPlus, Error> {
     let (path, after_path) = parse_path(path_plus)?;
     let (query, after_query) = parse_query(after_path)?;
     check_fragment(after_query)?;
 
     let path: &str = path.as_str();
-    let query_len: usize = query.map(|q| q.as_str().len()).unwrap_or(0);
+    let query: &str = query.map(|q| q.as_str()).unwrap_or("");
 
     Ok(PathPlus {
         path_len: path.len(),
-        canonical_path_len: canonical_path_len(path),
-        query_len,
+        canonical_path_len: canonical_path_len(path, normalizer),
+        query_len: query.len(),
+        canonical_query_len: canonical_query_len(query, normalizer),
+        fragment_len: after_query.len(),
+        canonical_fragment_len: canonical_fragment_len(after_query, normalizer),
     })
 }
 
@@ -32,44 +40,62 @@ pub fn parse_path_plus(path_plus: &str) -> Result<PathPlus, Error> {
 /// This is used when the URL has no explicit path. The path is implied to be a single '/' which is reflected in the
 /// returned `path_len` even though it is not present in `query_plus`.
 ///
-/// The query & fragment will be validated.
-pub fn parse_query_plus(query_plus: &str) -> Result<PathPlus, Error> {
+/// The query & fragment will be validated. The canonical lengths are as normalized by the `normalizer`.
+pub fn parse_query_plus(query_plus: &str, normalizer: Normalizer) -> Result<PathPlus, Error> {
     let (query, after_query) = parse_query(query_plus)?;
     check_fragment(after_query)?;
 
-    let query_len: usize = query.map(|q| q.as_str().len()).unwrap_or(0);
+    let query: &str = query.map(|q| q.as_str()).unwrap_or("");
 
     // The implied path is a single '/' which is already canonical.
     Ok(PathPlus {
         path_len: 1,
         canonical_path_len: 1,
-        query_len,
+        query_len: query.len(),
+        canonical_query_len: canonical_query_len(query, normalizer),
+        fragment_len: after_query.len(),
+        canonical_fragment_len: canonical_fragment_len(after_query, normalizer),
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Error;
     use crate::Error::{InvalidFragment, InvalidPath, InvalidQuery};
     use crate::parse::{PathPlus, parse_path_plus, parse_query_plus};
+    use crate::{Error, Normalizer};
 
-    /// Creates the expected path-plus. (for paths with no dot-segments)
-    fn path_plus(path_len: usize, query_len: usize) -> PathPlus {
+    /// Creates the expected path-plus. (for a path, query, & fragment that are already normalized)
+    fn path_plus(path_len: usize, query_len: usize, fragment_len: usize) -> PathPlus {
         PathPlus {
             path_len,
             canonical_path_len: path_len,
             query_len,
+            canonical_query_len: query_len,
+            fragment_len,
+            canonical_fragment_len: fragment_len,
         }
     }
 
     #[test]
     fn fn_parse_path_plus() {
         let test_cases: &[(&str, Result<PathPlus, Error>)] = &[
-            ("/", Ok(path_plus(1, 0))),
-            ("/p", Ok(path_plus(2, 0))),
-            ("/p?a=1", Ok(path_plus(2, 4))),
-            ("/p#frag", Ok(path_plus(2, 0))),
-            ("/p?a=1#frag", Ok(path_plus(2, 4))),
+            ("/", Ok(path_plus(1, 0, 0))),
+            ("/p", Ok(path_plus(2, 0, 0))),
+            ("/p?a=1", Ok(path_plus(2, 4, 0))),
+            ("/p#frag", Ok(path_plus(2, 0, 5))),
+            ("/p?a=1#frag", Ok(path_plus(2, 4, 5))),
+            // The escaped unreserved chars shorten every component once decoded.
+            (
+                "/%7e/..?%7e#%7e%41",
+                Ok(PathPlus {
+                    path_len: 7,
+                    canonical_path_len: 1,
+                    query_len: 4,
+                    canonical_query_len: 2,
+                    fragment_len: 7,
+                    canonical_fragment_len: 3,
+                }),
+            ),
             ("", Err(InvalidPath)),
             ("no-slash", Err(InvalidPath)),
             ("/p q", Err(InvalidPath)),
@@ -77,7 +103,7 @@ mod tests {
             ("/p#a b", Err(InvalidFragment)),
         ];
         for (input, expected) in test_cases {
-            let result: Result<PathPlus, Error> = parse_path_plus(input);
+            let result: Result<PathPlus, Error> = parse_path_plus(input, Normalizer::DEFAULT);
             assert_eq!(result, *expected, "input={}", input);
         }
     }
@@ -85,18 +111,18 @@ mod tests {
     #[test]
     fn fn_parse_query_plus() {
         let test_cases: &[(&str, Result<PathPlus, Error>)] = &[
-            ("", Ok(path_plus(1, 0))),
-            ("?", Ok(path_plus(1, 1))),
-            ("?a=1", Ok(path_plus(1, 4))),
-            ("#", Ok(path_plus(1, 0))),
-            ("#frag", Ok(path_plus(1, 0))),
-            ("?a=1#frag", Ok(path_plus(1, 4))),
+            ("", Ok(path_plus(1, 0, 0))),
+            ("?", Ok(path_plus(1, 1, 0))),
+            ("?a=1", Ok(path_plus(1, 4, 0))),
+            ("#", Ok(path_plus(1, 0, 1))),
+            ("#frag", Ok(path_plus(1, 0, 5))),
+            ("?a=1#frag", Ok(path_plus(1, 4, 5))),
             ("?a b", Err(InvalidQuery)),
             ("#a b", Err(InvalidFragment)),
             ("not-a-query", Err(InvalidFragment)),
         ];
         for (input, expected) in test_cases {
-            let result: Result<PathPlus, Error> = parse_query_plus(input);
+            let result: Result<PathPlus, Error> = parse_query_plus(input, Normalizer::DEFAULT);
             assert_eq!(result, *expected, "input={}", input);
         }
     }
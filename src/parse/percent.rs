@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+
+/// Gets the byte value of the percent-encoded octet at the `index` of `s`.
+///
+/// Returns `None` when the `index` is not a '%' char followed by two hex digits.
+const fn escaped_octet(s: &[u8], index: usize) -> Option<u8> {
+    if index + 2 >= s.len() || s[index] != b'%' {
+        return None;
+    }
+    match (hex_value(s[index + 1]), hex_value(s[index + 2])) {
+        (Some(high), Some(low)) => Some((high << 4) | low),
+        _ => None,
+    }
+}
+
+/// Gets the value of the hex digit `c`.
+const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Checks if the char `c` is unreserved.
+///
+/// # RFC 3986
+/// An unreserved char is equivalent to its percent-encoded octet, so the octet is decoded when normalizing.
+/// <https://www.rfc-editor.org/rfc/rfc3986#section-2.3>
+const fn is_unreserved(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'-' || c == b'.' || c == b'_' || c == b'~'
+}
+
+/// Gets the length of `s` with its percent-encoding normalized.
+///
+/// Every escaped unreserved char is decoded, which shortens it by 2. The hex digits are uppercased, which never changes
+/// the length.
+pub fn percent_normalized_len(s: &str) -> usize {
+    let bytes: &[u8] = s.as_bytes();
+    let decoded: usize = bytes
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| escaped_octet(bytes, *index).is_some_and(is_unreserved))
+        .count();
+    s.len() - (2 * decoded)
+}
+
+/// Writes `s` to `url` with its percent-encoding normalized.
+///
+/// The escaped unreserved chars are decoded & the hex digits of the other escapes are uppercased. A '%' char that does
+/// not begin an escape is written as it is, so `s` need not be validated.
+///
+/// # RFC 3986
+/// <https://www.rfc-editor.org/rfc/rfc3986#section-6.2.2.2>
+pub fn write_percent_normalized(s: &str, url: &mut String) {
+    let bytes: &[u8] = s.as_bytes();
+
+    // The text between the escapes is written as whole slices, so any multibyte chars are copied intact.
+    let mut written: usize = 0;
+    let mut index: usize = 0;
+    while index < bytes.len() {
+        if let Some(c) = escaped_octet(bytes, index) {
+            url.push_str(&s[written..index]);
+            if is_unreserved(c) {
+                url.push(c as char);
+            } else {
+                url.push('%');
+                url.push(bytes[index + 1].to_ascii_uppercase() as char);
+                url.push(bytes[index + 2].to_ascii_uppercase() as char);
+            }
+            index += 3;
+            written = index;
+        } else {
+            index += 1;
+        }
+    }
+    url.push_str(&s[written..]);
+}
+
+/// Normalizes the percent-encoding of `s`. (see [`write_percent_normalized`])
+///
+/// The string is borrowed when it is already normalized.
+pub fn normalize_percent(s: &str) -> Cow<'_, str> {
+    if is_percent_normalized(s.as_bytes()) {
+        Cow::Borrowed(s)
+    } else {
+        let mut normalized: String = String::with_capacity(percent_normalized_len(s));
+        write_percent_normalized(s, &mut normalized);
+        Cow::Owned(normalized)
+    }
+}
+
+/// Checks if the percent-encoding of `s` is normalized. (see [`write_percent_normalized`])
+///
+/// No escape is of an unreserved char & the hex digits of every escape are uppercase. This is const so the const
+/// parser checks the normalization the same way.
+pub const fn is_percent_normalized(s: &[u8]) -> bool {
+    let mut index: usize = 0;
+    while index < s.len() {
+        if let Some(c) = escaped_octet(s, index) {
+            if is_unreserved(c) || s[index + 1].is_ascii_lowercase() || s[index + 2].is_ascii_lowercase() {
+                return false;
+            }
+            index += 3;
+        } else {
+            index += 1;
+        }
+    }
+    true
+}
+
+/// Checks if the hex digits of every escape in `s` are uppercase.
+pub fn is_percent_uppercase(s: &str) -> bool {
+    let bytes: &[u8] = s.as_bytes();
+    (0..bytes.len()).all(|index| {
+        escaped_octet(bytes, index).is_none() || !bytes[index + 1..index + 3].iter().any(u8::is_ascii_lowercase)
+    })
+}
+
+/// Makes the hex digits of every escape in `s` uppercase.
+///
+/// The length never changes, so this normalizes the hex case in place like the letter case of the scheme & host.
+pub fn make_percent_uppercase(s: &mut str) {
+    let mut index: usize = 0;
+    while let Some(found) = s[index..].find('%') {
+        let at: usize = index + found;
+        if escaped_octet(s.as_bytes(), at).is_some() {
+            // The hex digits are ASCII, so the range is on char boundaries.
+            s[at + 1..at + 3].make_ascii_uppercase();
+        }
+        index = at + 1;
+    }
+}
+
+/// Counts the dots of the `segment` when it is a dot-segment.
+///
+/// Returns 1 for a "." segment, 2 for a ".." segment, & 0 otherwise. A `%2E` escape is a '.' char when the `percent`
+/// encoding is normalized, since it is decoded to one.
+pub const fn dot_segment_dots(segment: &str, percent: bool) -> usize {
+    let bytes: &[u8] = segment.as_bytes();
+    let mut dots: usize = 0;
+    let mut index: usize = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'.' {
+            index += 1;
+        } else if percent && matches!(escaped_octet(bytes, index), Some(b'.')) {
+            index += 3;
+        } else {
+            return 0;
+        }
+        dots += 1;
+    }
+    if dots <= 2 { dots } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        dot_segment_dots, is_percent_normalized, is_percent_uppercase, make_percent_uppercase, normalize_percent,
+        percent_normalized_len,
+    };
+    use std::borrow::Cow;
+
+    #[test]
+    fn fn_normalize_percent() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", ""),
+            ("abc", "abc"),
+            // The escaped unreserved chars are decoded.
+            ("%41%5a%61%7A%30%39", "AZaz09"),
+            ("%2D%2e%5F%7e", "-._~"),
+            // The other escapes have their hex digits uppercased.
+            ("%2f%3a%20%25", "%2F%3A%20%25"),
+            ("%c3%a9", "%C3%A9"),
+            ("a%2fb%7ec", "a%2Fb~c"),
+            // A '%' char that does not begin an escape is left as it is.
+            ("%", "%"),
+            ("%z1%7e", "%z1~"),
+            ("\u{e9}%7e\u{4f60}", "\u{e9}~\u{4f60}"),
+        ];
+        for (s, expected) in test_cases {
+            let result: Cow<str> = normalize_percent(s);
+            assert_eq!(result, *expected, "s={}", s);
+            assert_eq!(percent_normalized_len(s), expected.len(), "s={}", s);
+            // The string is borrowed exactly when it is already normalized.
+            assert_eq!(matches!(result, Cow::Borrowed(_)), *s == *expected, "s={}", s);
+            assert_eq!(is_percent_normalized(s.as_bytes()), *s == *expected, "s={}", s);
+        }
+    }
+
+    #[test]
+    fn fn_make_percent_uppercase() {
+        let test_cases: &[(&str, &str, bool)] = &[
+            ("", "", true),
+            ("abc", "abc", true),
+            ("%2F", "%2F", true),
+            ("%2f", "%2F", false),
+            ("a%2fb%e9c%7e", "a%2Fb%E9c%7E", false),
+            ("%zz%", "%zz%", true),
+        ];
+        for (s, expected, uppercase) in test_cases {
+            assert_eq!(is_percent_uppercase(s), *uppercase, "s={}", s);
+            let mut result: String = s.to_string();
+            make_percent_uppercase(result.as_mut_str());
+            assert_eq!(result, *expected, "s={}", s);
+        }
+    }
+
+    #[test]
+    fn fn_dot_segment_dots() {
+        // The third column is without the percent-encoding normalized, which leaves a `%2E` escape as it is.
+        let test_cases: &[(&str, usize, usize)] = &[
+            ("", 0, 0),
+            (".", 1, 1),
+            ("%2e", 1, 0),
+            ("%2E", 1, 0),
+            ("..", 2, 2),
+            (".%2e", 2, 0),
+            ("%2e.", 2, 0),
+            ("%2E%2e", 2, 0),
+            ("...", 0, 0),
+            ("a", 0, 0),
+            (".a", 0, 0),
+            ("%2ea", 0, 0),
+            ("%2f", 0, 0),
+            ("%2", 0, 0),
+        ];
+        for (segment, expected, expected_without_percent) in test_cases {
+            let result: usize = dot_segment_dots(segment, true);
+            assert_eq!(result, *expected, "segment={}", segment);
+            let result: usize = dot_segment_dots(segment, false);
+            assert_eq!(result, *expected_without_percent, "segment={}", segment);
+        }
+    }
+}
@@ -13,19 +13,26 @@ pub fn parse_host(s: &str) -> (&str, &str) {
     } else {
         s
     };
-    if host_and_port.is_empty() {
-        ("", s)
-    } else {
-        let bracketed: bool =
-            host_and_port.as_bytes()[0] == b'[' && host_and_port.as_bytes()[host_and_port.len() - 1] == b']';
-        if bracketed {
-            s.split_at(host_and_port.len())
-        } else if let Some(last_colon) = host_and_port.as_bytes().iter().rposition(|c| *c == b':') {
-            s.split_at(last_colon)
-        } else {
-            s.split_at(host_and_port.len())
+    s.split_at(host_len(host_and_port.as_bytes()))
+}
+
+/// Gets the length of the host at the start of the `host_and_port`, which is the authority without the user info.
+///
+/// The host ends at the last ':' unless it is a bracketed IPv6 address. This is const so the const parser splits the
+/// host & port the same way.
+pub const fn host_len(host_and_port: &[u8]) -> usize {
+    let len: usize = host_and_port.len();
+    if len == 0 || (host_and_port[0] == b'[' && host_and_port[len - 1] == b']') {
+        return len;
+    }
+    let mut index: usize = len;
+    while index > 0 {
+        index -= 1;
+        if host_and_port[index] == b':' {
+            return index;
         }
     }
+    len
 }
 
 /// Parses the optional IP address from the `host` string. If the host is not an IP address the domain will be validated
@@ -0,0 +1,97 @@
+use crate::Error;
+use crate::Error::InvalidHost;
+use address::Domain;
+
+/// Converts the Unicode domain `host` to its ASCII form.
+///
+/// The host is processed with UTS #46: the labels are mapped (case folded & NFC normalized), checked against the bidi &
+/// contextual rules, & Punycode encoded into A-labels. The converted domain is validated like an ASCII host.
+#[cfg(feature = "idna")]
+pub fn idna_to_ascii(host: &str) -> Result<Domain, Error> {
+    Domain::parse_unicode(host).map_err(|_| InvalidHost)
+}
+
+/// Converts the Unicode domain `host` to its ASCII form.
+///
+/// Unicode domains are not supported without the `idna` feature, so this always fails.
+#[cfg(not(feature = "idna"))]
+pub fn idna_to_ascii(_host: &str) -> Result<Domain, Error> {
+    Err(InvalidHost)
+}
+
+/// Checks the `xn--` labels of the ASCII domain `host`.
+///
+/// A label with the ACE prefix is only valid when it decodes to a U-label that UTS #46 accepts, so the host is
+/// processed like a Unicode domain when it has one. (`xn--zz` & `xn--a` are rejected)
+#[cfg(feature = "idna")]
+pub fn check_a_labels(host: &str) -> Result<(), Error> {
+    if host.split('.').any(is_a_label) {
+        let _: Domain = idna_to_ascii(host)?;
+    }
+    Ok(())
+}
+
+/// Checks the `xn--` labels of the ASCII domain `host`.
+///
+/// The A-labels cannot be decoded without the `idna` feature, so they are accepted as they are.
+#[cfg(not(feature = "idna"))]
+pub fn check_a_labels(_host: &str) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Checks if the `label` has the `xn--` ACE prefix, ignoring the letter case.
+#[cfg(feature = "idna")]
+fn is_a_label(label: &str) -> bool {
+    label.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"))
+}
+
+#[cfg(all(test, feature = "idna"))]
+mod tests {
+    use crate::Error;
+    use crate::Error::InvalidHost;
+    use crate::parse::{check_a_labels, idna_to_ascii};
+
+    #[test]
+    fn fn_idna_to_ascii() {
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("bücher.example", Ok("xn--bcher-kva.example")),
+            ("BÜCHER.Example", Ok("xn--bcher-kva.example")),
+            ("münchen.de", Ok("xn--mnchen-3ya.de")),
+            ("例え.テスト", Ok("xn--r8jz45g.xn--zckzah")),
+            ("ｅｘａｍｐｌｅ.com", Ok("example.com")),
+            ("faß.de", Ok("xn--fa-hia.de")),
+            ("bücher。example", Ok("xn--bcher-kva.example")),
+            // The bidi rule rejects a label that mixes right-to-left & left-to-right chars.
+            ("אa.example", Err(InvalidHost)),
+            // The contextual rule rejects a zero width joiner that does not follow a virama.
+            ("a\u{200D}ü.example", Err(InvalidHost)),
+            ("bü cher.example", Err(InvalidHost)),
+            ("bü_cher.example", Err(InvalidHost)),
+            ("bücher..example", Err(InvalidHost)),
+        ];
+        for (host, expected) in test_cases {
+            let result: Result<String, Error> = idna_to_ascii(host).map(|domain| domain.name().to_string());
+            assert_eq!(result, expected.map(str::to_string), "host={}", host);
+        }
+    }
+
+    #[test]
+    fn fn_check_a_labels() {
+        let test_cases: &[(&str, Result<(), Error>)] = &[
+            ("example.com", Ok(())),
+            ("xn--bcher-kva.example", Ok(())),
+            ("XN--BCHER-KVA.example", Ok(())),
+            ("xn--r8jz45g.xn--zckzah", Ok(())),
+            // The label must decode as Punycode.
+            ("xn--zz", Err(InvalidHost)),
+            ("xn--a.example", Err(InvalidHost)),
+            ("www.XN--A.example", Err(InvalidHost)),
+            // The decoded label must be a valid U-label.
+            ("xn--abc-.example", Err(InvalidHost)),
+        ];
+        for (host, expected) in test_cases {
+            let result: Result<(), Error> = check_a_labels(host);
+            assert_eq!(result, *expected, "host={}", host);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) use canonical_host::*;
 pub(crate) use canonical_port::*;
 pub(crate) use host::*;
+pub(crate) use idna_host::*;
 pub(crate) use port::*;
 pub(crate) use pre_path::*;
 pub(crate) use scheme::*;
@@ -10,6 +11,7 @@ pub(crate) use user_info::*;
 mod canonical_host;
 mod canonical_port;
 mod host;
+mod idna_host;
 mod port;
 mod pre_path;
 mod scheme;
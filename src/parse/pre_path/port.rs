@@ -1,7 +1,6 @@
 use crate::Error;
 use crate::Error::InvalidPort;
 use crate::parse::is_authority_end;
-use std::str::FromStr;
 
 /// Parses the port from the prefix of `s`.
 ///
@@ -21,22 +20,37 @@ pub fn parse_port(s: &str) -> Result<(Option<u16>, &str), Error> {
             .position(|c| is_authority_end(*c))
             .unwrap_or(s.len());
         let (digits, rest) = s.split_at(end);
-
-        // The `u16::from_str` fn accepts a leading '+' char which the RFC does not allow, so the digits are validated
-        // explicitly before the port is parsed.
-        if !digits.as_bytes().iter().all(|c| c.is_ascii_digit()) {
-            Err(InvalidPort)
-        } else if digits.is_empty() {
-            Ok((None, rest))
-        } else {
-            let port: u16 = u16::from_str(digits).map_err(|_| InvalidPort)?;
-            Ok((Some(port), rest))
-        }
+        Ok((parse_port_digits(digits.as_bytes())?, rest))
     } else {
         Ok((None, s))
     }
 }
 
+/// Parses the port `digits`, which follow the ':'.
+///
+/// Returns `None` when the `digits` are empty. This is const so the const parser parses the port the same way.
+///
+/// # RFC 3986
+/// The port is `*DIGIT`, so a sign is not allowed even though `u16::from_str` takes a leading '+' char.
+pub const fn parse_port_digits(digits: &[u8]) -> Result<Option<u16>, Error> {
+    if digits.is_empty() {
+        return Ok(None);
+    }
+    let mut port: u32 = 0;
+    let mut index: usize = 0;
+    while index < digits.len() {
+        if !digits[index].is_ascii_digit() {
+            return Err(InvalidPort);
+        }
+        port = port * 10 + (digits[index] - b'0') as u32;
+        if port > u16::MAX as u32 {
+            return Err(InvalidPort);
+        }
+        index += 1;
+    }
+    Ok(Some(port as u16))
+}
+
 /// Gets the number of decimal digits in the `port`.
 pub const fn port_decimal_len(port: u16) -> usize {
     if port < 10 {
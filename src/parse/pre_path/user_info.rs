@@ -1,5 +1,6 @@
 use crate::Error;
-use crate::Error::UserInfoNotSupported;
+use crate::Error::{InvalidUserInfo, UserInfoNotSupported};
+use crate::UserInfo;
 use crate::parse::is_authority_end;
 
 /// Checks that the authority at the prefix of `s` has no user info.
@@ -26,11 +27,33 @@ pub fn check_no_user_info(s: &str) -> Result<(), Error> {
     }
 }
 
+/// Parses the length of the optional user info at the prefix of `s`. (including the '@')
+///
+/// Returns the length & the remaining string after the '@'. The length is 0 when there is no user info.
+///
+/// # RFC 3986
+/// The user info ends at the last '@' char in the authority. The '@' char is not a user info char, so an earlier '@'
+/// char makes the user info invalid rather than moving the host.
+/// <https://www.rfc-editor.org/rfc/rfc3986#section-3.2.1>
+pub fn parse_user_info_len(s: &str) -> Result<(usize, &str), Error> {
+    let end: usize = s
+        .as_bytes()
+        .iter()
+        .position(|c| is_authority_end(*c))
+        .unwrap_or(s.len());
+
+    match s.as_bytes()[..end].iter().rposition(|c| *c == b'@') {
+        Some(at) if UserInfo::is_valid(&s[..at]) => Ok((at + 1, &s[at + 1..])),
+        Some(_) => Err(InvalidUserInfo),
+        None => Ok((0, s)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Error;
-    use crate::Error::UserInfoNotSupported;
-    use crate::parse::check_no_user_info;
+    use crate::Error::{InvalidUserInfo, UserInfoNotSupported};
+    use crate::parse::{check_no_user_info, parse_user_info_len};
 
     #[test]
     fn fn_check_no_user_info() {
@@ -65,4 +88,38 @@ mod tests {
             assert_eq!(result, *expected, "s={}", s);
         }
     }
+
+    /// The user info length & the remaining string.
+    type Parsed<'a> = (usize, &'a str);
+
+    #[test]
+    fn fn_parse_user_info_len() {
+        let test_cases: &[(&str, Result<Parsed, Error>)] = &[
+            // No user info.
+            ("", Ok((0, ""))),
+            ("host", Ok((0, "host"))),
+            ("host:80/path", Ok((0, "host:80/path"))),
+            ("host/a@b", Ok((0, "host/a@b"))),
+            ("host?a@b", Ok((0, "host?a@b"))),
+            ("host#a@b", Ok((0, "host#a@b"))),
+            // User info in every form.
+            ("user@host", Ok((5, "host"))),
+            ("user:pass@host", Ok((10, "host"))),
+            ("user:@host", Ok((6, "host"))),
+            (":pass@host", Ok((6, "host"))),
+            ("@host", Ok((1, "host"))),
+            ("u%40x:p%3Aw@host:8080/p", Ok((12, "host:8080/p"))),
+            ("user@[::1]:80/p", Ok((5, "[::1]:80/p"))),
+            ("user@host/a@b", Ok((5, "host/a@b"))),
+            // The user info ends at the last '@' char, which makes an earlier one invalid.
+            ("a@b@host", Err(InvalidUserInfo)),
+            ("us er@host", Err(InvalidUserInfo)),
+            ("user%zz@host", Err(InvalidUserInfo)),
+            ("[::1]@host", Err(InvalidUserInfo)),
+        ];
+        for (s, expected) in test_cases {
+            let result: Result<Parsed, Error> = parse_user_info_len(s);
+            assert_eq!(result, *expected, "s={}", s);
+        }
+    }
 }
@@ -0,0 +1,110 @@
+/// The components of a URI reference.
+///
+/// The components are **not** validated. A component is `None` when its delimiter is absent, which is distinct from
+/// an empty component: the reference `"?"` has an empty query while the reference `""` has no query at all.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Reference<'a> {
+    pub scheme: Option<&'a str>,    // the scheme without the ':'
+    pub authority: Option<&'a str>, // the authority without the "//"
+    pub path: &'a str,              // the path (may be empty)
+    pub query: Option<&'a str>,     // the query including the '?'
+    pub fragment: Option<&'a str>,  // the fragment including the '#'
+}
+
+/// Splits the URI reference `s` into its components.
+///
+/// # RFC 3986
+/// The components are split as with the regular expression of Appendix B, which accepts every string. A scheme is any
+/// run of chars before a ':' that precedes the first '/', '?', or '#' char, so it may still be invalid.
+/// <https://www.rfc-editor.org/rfc/rfc3986#appendix-B>
+pub fn parse_reference(s: &str) -> Reference<'_> {
+    let (fragment, s) = match s.find('#') {
+        Some(hash) => (Some(&s[hash..]), &s[..hash]),
+        None => (None, s),
+    };
+    let (query, s) = match s.find('?') {
+        Some(question) => (Some(&s[question..]), &s[..question]),
+        None => (None, s),
+    };
+    let (scheme, s) = match s.as_bytes().iter().position(|c| *c == b':' || *c == b'/') {
+        Some(colon) if colon != 0 && s.as_bytes()[colon] == b':' => (Some(&s[..colon]), &s[colon + 1..]),
+        _ => (None, s),
+    };
+    let (authority, path) = match s.strip_prefix("//") {
+        Some(s) => {
+            let end: usize = s.find('/').unwrap_or(s.len());
+            (Some(&s[..end]), &s[end..])
+        }
+        None => (None, s),
+    };
+
+    Reference {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{Reference, parse_reference};
+
+    /// Creates the expected reference.
+    fn reference<'a>(
+        scheme: Option<&'a str>,
+        authority: Option<&'a str>,
+        path: &'a str,
+        query: Option<&'a str>,
+        fragment: Option<&'a str>,
+    ) -> Reference<'a> {
+        Reference {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+
+    #[test]
+    fn fn_parse_reference() {
+        let test_cases: &[(&str, Reference)] = &[
+            ("", reference(None, None, "", None, None)),
+            ("g", reference(None, None, "g", None, None)),
+            ("../g", reference(None, None, "../g", None, None)),
+            ("/g", reference(None, None, "/g", None, None)),
+            ("?y", reference(None, None, "", Some("?y"), None)),
+            ("#s", reference(None, None, "", None, Some("#s"))),
+            ("g?y#s", reference(None, None, "g", Some("?y"), Some("#s"))),
+            ("?", reference(None, None, "", Some("?"), None)),
+            ("#", reference(None, None, "", None, Some("#"))),
+            // The '?' & '/' chars are not delimiters within the fragment.
+            ("g#s/../x?y", reference(None, None, "g", None, Some("#s/../x?y"))),
+            ("g?y/../x", reference(None, None, "g", Some("?y/../x"), None)),
+            ("//g", reference(None, Some("g"), "", None, None)),
+            ("//g/p?y", reference(None, Some("g"), "/p", Some("?y"), None)),
+            ("//g?y", reference(None, Some("g"), "", Some("?y"), None)),
+            ("//", reference(None, Some(""), "", None, None)),
+            (
+                "http://a/b?q#f",
+                reference(Some("http"), Some("a"), "/b", Some("?q"), Some("#f")),
+            ),
+            ("g:h", reference(Some("g"), None, "h", None, None)),
+            ("http:g", reference(Some("http"), None, "g", None, None)),
+            // A ':' after the first '/' char is part of the path.
+            ("./g:h", reference(None, None, "./g:h", None, None)),
+            ("/g:h", reference(None, None, "/g:h", None, None)),
+            // A ':' after the query or fragment delimiter is not a scheme delimiter.
+            ("?g:h", reference(None, None, "", Some("?g:h"), None)),
+            ("#g:h", reference(None, None, "", None, Some("#g:h"))),
+            // An empty scheme is not a scheme.
+            (":g", reference(None, None, ":g", None, None)),
+        ];
+        for (s, expected) in test_cases {
+            let result: Reference = parse_reference(s);
+            assert_eq!(result, *expected, "s={}", s);
+        }
+    }
+}
@@ -1,37 +1,41 @@
-use crate::InvalidUrlError;
-use crate::WebUrl;
-use crate::parse::{Parts, finalize_web_url, parse_parts, write_normalized};
+use crate::parse::{Parts, diagnose, finalize_web_url, parse_parts, write_normalized};
+use crate::{InvalidUrlError, Normalizer, WebUrl};
 
 impl TryFrom<String> for WebUrl {
     type Error = InvalidUrlError;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
-        let parts: Parts = match parse_parts(s.as_str()) {
-            Ok(parts) => parts,
-            Err(error) => return Err(InvalidUrlError::new(error, s)),
-        };
+        parse_string(s, Normalizer::DEFAULT)
+    }
+}
 
-        // The URL is validated before it is modified so the string is returned unchanged on error.
-        let url: String = if parts.is_normalized() {
-            // The string is already normalized so it is reused without allocating.
-            s
-        } else if !parts.needs_rewrite() {
-            // Only the '/' is missing. The reservation is exact so the string grows at most once.
-            let mut url: String = s;
-            url.reserve_exact(1);
-            url.insert(parts.slash_index(), '/');
-            url
-        } else {
-            // The host or port changes length once normalized so the string is rebuilt with a single exactly-sized
-            // allocation.
-            let mut url: String = String::with_capacity(parts.normalized_len(s.len()));
-            write_normalized(s.as_str(), parts, &mut url);
-            url
-        };
+/// Parses the web-based URL `s` & normalizes it with the `normalizer`, reusing its allocation when possible.
+pub fn parse_string(s: String, normalizer: Normalizer) -> Result<WebUrl, InvalidUrlError> {
+    let parts: Parts = match parse_parts(s.as_str(), normalizer) {
+        Ok(parts) => parts,
+        Err(error) => return Err(InvalidUrlError::new(diagnose(&s, error, normalizer), s)),
+    };
 
-        unsafe { finalize_web_url(url, parts.pre_path, parts.path_plus) }
-            .map_err(|(error, url)| InvalidUrlError::new(error, url))
-    }
+    // The URL is validated before it is modified so the string is returned unchanged on error.
+    let url: String = if parts.is_normalized() {
+        // The string is already normalized so it is reused without allocating.
+        s
+    } else if !parts.needs_rewrite() {
+        // Only the '/' is missing. The reservation is exact so the string grows at most once.
+        let mut url: String = s;
+        url.reserve_exact(1);
+        url.insert(parts.slash_index(), '/');
+        url
+    } else {
+        // The host, port, path, query, or fragment changes length once normalized so the string is rebuilt with a
+        // single exactly-sized allocation.
+        let mut url: String = String::with_capacity(parts.normalized_len());
+        write_normalized(s.as_str(), &parts, &mut url);
+        url
+    };
+
+    unsafe { finalize_web_url(url, parts) }
+        .map_err(|(error, url)| InvalidUrlError::new(diagnose(&url, error, normalizer), url))
 }
 
 #[cfg(test)]
@@ -50,11 +54,15 @@ mod tests {
             ("http://host", Ok("http://host/")),
             ("http://host?q#f", Ok("http://host/?q#f")),
             // The port is rewritten: the URL is rebuilt.
-            ("http://host:0080/p", Ok("http://host:80/p")),
+            ("http://host:0080/p", Ok("http://host/p")),
+            ("http://host:08080/p", Ok("http://host:8080/p")),
             ("http://host:?q", Ok("http://host/?q")),
+            // The percent-encoding is rewritten when an escape is decoded & uppercased in place otherwise.
+            ("http://host/%7e?%7e#%7e", Ok("http://host/~?~#~")),
+            ("http://host/%2f?%2f#%2f", Ok("http://host/%2F?%2F#%2F")),
             // The letter case is normalized in every branch.
             ("HTTP://HOST", Ok("http://host/")),
-            ("HTTP://HOST:0080/P", Ok("http://host:80/P")),
+            ("HTTP://HOST:08080/P", Ok("http://host:8080/P")),
             // Errors return the original string unchanged.
             ("not a url", Err(InvalidScheme)),
             ("http://host:bad", Err(InvalidPort)),
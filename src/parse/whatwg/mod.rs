@@ -0,0 +1,7 @@
+pub(crate) use whatwg::*;
+pub(crate) use whatwg_host::*;
+pub(crate) use whatwg_ip::*;
+
+mod whatwg;
+mod whatwg_host;
+mod whatwg_ip;
@@ -0,0 +1,62 @@
+use crate::Error;
+use crate::Error::InvalidHost;
+use crate::parse::{CanonicalHost, ends_in_number, parse_whatwg_ipv4, parse_whatwg_ipv6};
+use crate::percent::decode_bytes;
+use address::{IPv4Address, IPv6Address};
+use std::borrow::Cow;
+
+/// Parses the WHATWG `host` of a URL with a special scheme & writes it to the `url`.
+///
+/// A bracketed host is an IPv6 address. Otherwise the host is percent-decoded & converted to ASCII, & it is an IPv4
+/// address when its last label is a number. The IPv4 address may be written with fewer than four numbers & with octal
+/// or hex numbers. (`0x7f.1` -> `127.0.0.1`) The IP addresses are written in their canonical form.
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#concept-host-parser>
+pub fn write_whatwg_host(host: &str, url: &mut String) -> Result<(), Error> {
+    if let Some(ip) = host.strip_prefix('[') {
+        let ip: &str = ip.strip_suffix(']').ok_or(InvalidHost)?;
+        let ip: IPv6Address = IPv6Address::from(parse_whatwg_ipv6(ip).ok_or(InvalidHost)?);
+        url.push_str(CanonicalHost::new(ip.to_ip()).as_str());
+        return Ok(());
+    }
+
+    // A '%' that does not begin an escape is a forbidden domain char, so it fails like the invalid UTF-8 does.
+    let decoded: Cow<[u8]> = decode_bytes(host).map_err(|_| InvalidHost)?;
+    let domain: &str = std::str::from_utf8(&decoded).map_err(|_| InvalidHost)?;
+    let domain: Cow<str> = domain_to_ascii(domain)?;
+    if ends_in_number(&domain) {
+        let ip: IPv4Address = IPv4Address::from(parse_whatwg_ipv4(&domain).ok_or(InvalidHost)?);
+        url.push_str(CanonicalHost::new(ip.to_ip()).as_str());
+    } else {
+        url.push_str(&domain);
+    }
+    Ok(())
+}
+
+/// Converts the `domain` to ASCII with the UTS #46 processing of the WHATWG URL Standard.
+///
+/// The hyphens & the DNS length are not checked but the forbidden domain chars are rejected.
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#concept-domain-to-ascii>
+#[cfg(feature = "idna")]
+fn domain_to_ascii(domain: &str) -> Result<Cow<'_, str>, Error> {
+    match idna::domain_to_ascii_cow(domain.as_bytes(), idna::AsciiDenyList::URL) {
+        Ok(domain) if !domain.is_empty() => Ok(domain),
+        _ => Err(InvalidHost),
+    }
+}
+
+/// Converts the `domain` to ASCII.
+///
+/// Unicode domains are not supported without the `idna` feature, so only an ASCII domain is accepted & it is
+/// lowercased. Its chars are validated when the rewritten URL is parsed.
+#[cfg(not(feature = "idna"))]
+fn domain_to_ascii(domain: &str) -> Result<Cow<'_, str>, Error> {
+    if domain.is_empty() || !domain.is_ascii() {
+        Err(InvalidHost)
+    } else {
+        Ok(Cow::Owned(domain.to_ascii_lowercase()))
+    }
+}
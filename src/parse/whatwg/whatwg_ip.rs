@@ -0,0 +1,256 @@
+/// Checks if the ASCII `domain` ends in a number, in which case the host is an IPv4 address.
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#ends-in-a-number-checker>
+pub fn ends_in_number(domain: &str) -> bool {
+    let domain: &str = domain.strip_suffix('.').unwrap_or(domain);
+    let last: &str = domain.rsplit('.').next().unwrap_or_default();
+    (!last.is_empty() && last.bytes().all(|c| c.is_ascii_digit())) || parse_ipv4_number(last).is_some()
+}
+
+/// Parses the WHATWG IPv4 number `s`. A "0x" prefix makes it hex & a "0" prefix makes it octal.
+///
+/// The number saturates rather than overflowing since any number over 32 bits is invalid.
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#ipv4-number-parser>
+fn parse_ipv4_number(s: &str) -> Option<u64> {
+    if s.is_empty() {
+        return None;
+    }
+    let (digits, radix): (&str, u32) = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        (hex, 16)
+    } else if s.len() > 1
+        && let Some(octal) = s.strip_prefix('0')
+    {
+        (octal, 8)
+    } else {
+        (s, 10)
+    };
+    digits.chars().try_fold(0u64, |number, c| {
+        let digit: u32 = c.to_digit(radix)?;
+        Some(number.saturating_mul(radix as u64).saturating_add(digit as u64))
+    })
+}
+
+/// Parses the WHATWG IPv4 address `domain`.
+///
+/// The address has one to four numbers & the last number fills the remaining bytes. (`127.1` -> `127.0.0.1`)
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#concept-ipv4-parser>
+pub fn parse_whatwg_ipv4(domain: &str) -> Option<u32> {
+    let domain: &str = domain.strip_suffix('.').unwrap_or(domain);
+    let mut numbers: [u64; 4] = [0; 4];
+    let mut count: usize = 0;
+    for part in domain.split('.') {
+        if count == numbers.len() {
+            return None;
+        }
+        numbers[count] = parse_ipv4_number(part)?;
+        count += 1;
+    }
+
+    let (last, init) = numbers[..count].split_last()?;
+    if init.iter().any(|number| *number > 255) || *last >= 256u64.pow(5 - count as u32) {
+        return None;
+    }
+    let address: u64 = init
+        .iter()
+        .enumerate()
+        .map(|(index, number)| number << (8 * (3 - index)))
+        .sum::<u64>()
+        + last;
+    Some(address as u32)
+}
+
+/// Parses the WHATWG IPv6 address `s` without its '[]' brackets into its pieces.
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#concept-ipv6-parser>
+pub fn parse_whatwg_ipv6(s: &str) -> Option<[u16; 8]> {
+    let s: &[u8] = s.as_bytes();
+    let mut address: [u16; 8] = [0; 8];
+    let mut piece_index: usize = 0;
+    let mut compress: Option<usize> = None;
+    let mut pointer: usize = 0;
+
+    if s.first() == Some(&b':') {
+        if s.get(1) != Some(&b':') {
+            return None;
+        }
+        pointer += 2;
+        piece_index += 1;
+        compress = Some(piece_index);
+    }
+    while pointer < s.len() {
+        if piece_index == 8 {
+            return None;
+        }
+        if s[pointer] == b':' {
+            if compress.is_some() {
+                return None;
+            }
+            pointer += 1;
+            piece_index += 1;
+            compress = Some(piece_index);
+            continue;
+        }
+
+        let mut value: u16 = 0;
+        let mut length: usize = 0;
+        while length < 4 && pointer < s.len() && s[pointer].is_ascii_hexdigit() {
+            value = value * 0x10 + (s[pointer] as char).to_digit(16)? as u16;
+            pointer += 1;
+            length += 1;
+        }
+        match s.get(pointer) {
+            Some(b'.') => {
+                // The last 32 bits are written as an IPv4 address. (`::ffff:1.2.3.4`)
+                if length == 0 || piece_index > 6 {
+                    return None;
+                }
+                pointer -= length;
+                let mut numbers_seen: usize = 0;
+                while pointer < s.len() {
+                    if numbers_seen > 0 {
+                        if s[pointer] == b'.' && numbers_seen < 4 {
+                            pointer += 1;
+                        } else {
+                            return None;
+                        }
+                    }
+                    if !s.get(pointer).is_some_and(u8::is_ascii_digit) {
+                        return None;
+                    }
+                    let mut ipv4_piece: Option<u16> = None;
+                    while let Some(c) = s.get(pointer).filter(|c| c.is_ascii_digit()) {
+                        let number: u16 = (c - b'0') as u16;
+                        ipv4_piece = match ipv4_piece {
+                            None => Some(number),
+                            Some(0) => return None,
+                            Some(piece) => Some(piece * 10 + number),
+                        };
+                        if ipv4_piece > Some(255) {
+                            return None;
+                        }
+                        pointer += 1;
+                    }
+                    address[piece_index] = address[piece_index] * 0x100 + ipv4_piece?;
+                    numbers_seen += 1;
+                    if numbers_seen == 2 || numbers_seen == 4 {
+                        piece_index += 1;
+                    }
+                }
+                if numbers_seen != 4 {
+                    return None;
+                }
+                break;
+            }
+            Some(b':') => {
+                pointer += 1;
+                if pointer == s.len() {
+                    return None;
+                }
+            }
+            Some(_) => return None,
+            None => {}
+        }
+        address[piece_index] = value;
+        piece_index += 1;
+    }
+
+    // The compressed zeros are expanded by moving the pieces after the "::" to the end.
+    match compress {
+        Some(compress) => {
+            let mut swaps: usize = piece_index - compress;
+            piece_index = 7;
+            while piece_index != 0 && swaps > 0 {
+                address.swap(piece_index, compress + swaps - 1);
+                piece_index -= 1;
+                swaps -= 1;
+            }
+        }
+        None if piece_index != 8 => return None,
+        None => {}
+    }
+    Some(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{ends_in_number, parse_whatwg_ipv4, parse_whatwg_ipv6};
+
+    #[test]
+    fn fn_ends_in_number() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", false),
+            (".", false),
+            ("example.com", false),
+            ("1.2.3.4", true),
+            ("1.2.3.4.", true),
+            ("example.1", true),
+            ("example.0x", true),
+            ("example.0xg", false),
+            ("example.09", true),
+            ("1.2.3.4..", false),
+        ];
+        for (domain, expected) in test_cases {
+            assert_eq!(ends_in_number(domain), *expected, "domain={}", domain);
+        }
+    }
+
+    #[test]
+    fn fn_parse_whatwg_ipv4() {
+        let test_cases: &[(&str, Option<u32>)] = &[
+            ("127.0.0.1", Some(0x7F000001)),
+            ("127.0.0.1.", Some(0x7F000001)),
+            ("127.1", Some(0x7F000001)),
+            ("0x7f.1", Some(0x7F000001)),
+            ("0177.0.0.01", Some(0x7F000001)),
+            ("2130706433", Some(0x7F000001)),
+            ("0x", Some(0)),
+            ("255.255.255.255", Some(u32::MAX)),
+            ("4294967295", Some(u32::MAX)),
+            ("4294967296", None),
+            ("256.0.0.1", None),
+            ("1.2.3.256", None),
+            ("1.2.65536", None),
+            ("1.2.3.4.5", None),
+            ("1..2", None),
+            ("09.1", None),
+            ("", None),
+        ];
+        for (domain, expected) in test_cases {
+            assert_eq!(parse_whatwg_ipv4(domain), *expected, "domain={}", domain);
+        }
+    }
+
+    #[test]
+    fn fn_parse_whatwg_ipv6() {
+        let test_cases: &[(&str, Option<[u16; 8]>)] = &[
+            ("::", Some([0; 8])),
+            ("::1", Some([0, 0, 0, 0, 0, 0, 0, 1])),
+            ("1::", Some([1, 0, 0, 0, 0, 0, 0, 0])),
+            ("1:2:3:4:5:6:7:8", Some([1, 2, 3, 4, 5, 6, 7, 8])),
+            ("1:2::7:8", Some([1, 2, 0, 0, 0, 0, 7, 8])),
+            ("::ffff:1.2.3.4", Some([0, 0, 0, 0, 0, 0xFFFF, 0x102, 0x304])),
+            ("0001:0::", Some([1, 0, 0, 0, 0, 0, 0, 0])),
+            ("1:2:3:4:5:6:7:8:9", None),
+            ("1:2:3:4:5:6:7", None),
+            ("1::2::3", None),
+            (":1", None),
+            ("1:", None),
+            ("12345::", None),
+            ("::1.2.3", None),
+            ("::1.2.3.4.5", None),
+            ("::01.2.3.4", None),
+            ("::256.2.3.4", None),
+            ("1:2:3:4:5:6:7:1.2.3.4", None),
+            ("::g", None),
+        ];
+        for (s, expected) in test_cases {
+            assert_eq!(parse_whatwg_ipv6(s), *expected, "s={}", s);
+        }
+    }
+}
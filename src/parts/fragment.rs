@@ -1,7 +1,8 @@
 use crate::Error;
 use crate::Error::InvalidFragment;
 use crate::parse;
-use std::borrow::Borrow;
+use crate::percent;
+use std::borrow::{Borrow, Cow};
 use std::fmt::{Debug, Display, Formatter};
 
 /// A web-based URL fragment.
@@ -81,6 +82,20 @@ impl<'a> Fragment<'a> {
     }
 }
 
+impl<'a> Fragment<'a> {
+    //! Decoding
+
+    /// Gets the percent-decoded fragment value. (will not contain the '#' prefix)
+    ///
+    /// The value is borrowed when there is nothing to decode.
+    ///
+    /// # Errors
+    /// Returns `InvalidUtf8` when the decoded value is not valid UTF-8.
+    pub fn decoded(self) -> Result<Cow<'a, str>, Error> {
+        percent::decode(self.value())
+    }
+}
+
 impl<'a> PartialEq<str> for Fragment<'a> {
     fn eq(&self, other: &str) -> bool {
         self.fragment == other
@@ -143,7 +158,9 @@ impl<'a> Display for Fragment<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Fragment;
+    use crate::Error::InvalidUtf8;
+    use crate::{Error, Fragment};
+    use std::borrow::Cow;
 
     #[test]
     fn is_valid() {
@@ -180,4 +197,21 @@ mod tests {
             assert_eq!(result, *expected, "fragment={}", fragment);
         }
     }
+
+    #[test]
+    fn decoded() {
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("#", Ok("")),
+            ("#section", Ok("section")),
+            // The '+' char is not a space outside of a query.
+            ("#a+b%20c", Ok("a+b c")),
+            ("#%C3%A9", Ok("\u{e9}")),
+            ("#%FF", Err(InvalidUtf8)),
+        ];
+        for (fragment, expected) in test_cases {
+            let fragment: Fragment = Fragment::try_from(*fragment).unwrap();
+            let result: Result<Cow<str>, Error> = fragment.decoded();
+            assert_eq!(result.as_deref().map_err(|e| *e), *expected, "fragment={}", fragment);
+        }
+    }
 }
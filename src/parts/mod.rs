@@ -4,10 +4,14 @@ pub use path::*;
 pub use piece_iterator::*;
 pub use query::*;
 pub use scheme::*;
+pub use user_info::*;
 
 mod fragment;
 mod param;
 mod path;
 mod piece_iterator;
 mod query;
+#[cfg(feature = "serde")]
+mod query_serde;
 mod scheme;
+mod user_info;
@@ -1,6 +1,8 @@
 use crate::Error;
 use crate::Error::InvalidParam;
 use crate::parse;
+use crate::percent;
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 
 /// A web-based URL query parameter.
@@ -135,6 +137,26 @@ impl<'a> Param<'a> {
     }
 }
 
+impl<'a> Param<'a> {
+    //! Decoding
+
+    /// Gets the percent-decoded name.
+    ///
+    /// The name is decoded as `application/x-www-form-urlencoded`, so a '+' char decodes to a space. The name is
+    /// borrowed when there is nothing to decode.
+    ///
+    /// # Errors
+    /// Returns `InvalidUtf8` when the decoded name is not valid UTF-8.
+    pub fn decoded_name(self) -> Result<Cow<'a, str>, Error> {
+        percent::decode_form(self.name)
+    }
+
+    /// Gets the percent-decoded optional value. (see [`Self::decoded_name`])
+    pub fn decoded_value(self) -> Result<Option<Cow<'a, str>>, Error> {
+        self.value.map(percent::decode_form).transpose()
+    }
+}
+
 impl<'a> PartialEq<str> for Param<'a> {
     fn eq(&self, other: &str) -> bool {
         Self::split(other) == (self.name, self.value)
@@ -196,7 +218,8 @@ impl<'a> Display for Param<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Param;
+    use crate::Error::InvalidUtf8;
+    use crate::{Error, Param};
 
     type ParamParts<'a> = (&'a str, Option<&'a str>);
 
@@ -258,6 +281,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decoded() {
+        let test_cases: &[(&str, Result<ParamParts, Error>)] = &[
+            ("name", Ok(("name", None))),
+            ("name=", Ok(("name", Some("")))),
+            ("a%20b=c%3Dd", Ok(("a b", Some("c=d")))),
+            ("a+b=c+d", Ok(("a b", Some("c d")))),
+            ("a%2Bb=%26", Ok(("a+b", Some("&")))),
+            ("%C3%A9=%E4%BD%A0", Ok(("\u{e9}", Some("\u{4f60}")))),
+            ("%80=b", Err(InvalidUtf8)),
+            ("a=%80", Err(InvalidUtf8)),
+        ];
+        for (param, expected) in test_cases {
+            let param: Param = Param::try_from(*param).unwrap();
+            match expected {
+                Ok((name, value)) => {
+                    assert_eq!(param.decoded_name().unwrap(), *name, "param={}", param);
+                    assert_eq!(param.decoded_value().unwrap().as_deref(), *value, "param={}", param);
+                }
+                Err(expected) => {
+                    let result: Result<_, Error> = param.decoded_name().and(param.decoded_value());
+                    assert_eq!(result.unwrap_err(), *expected, "param={}", param);
+                }
+            }
+        }
+    }
+
     #[test]
     fn display() {
         let param: Param = Param::new("a", Some("1")).unwrap();
@@ -0,0 +1,72 @@
+use crate::{Query, QuerySerdeError};
+use serde::Deserialize;
+
+impl<'a> Query<'a> {
+    //! Serde
+
+    /// Deserializes the query params into a typed value.
+    ///
+    /// The params have the `application/x-www-form-urlencoded` semantics: the names & values are percent-decoded, a '+'
+    /// char is decoded as a space, a repeated name fills a `Vec` field, & a missing or empty value of an `Option`
+    /// field is `None`. A value that needs no decoding can be borrowed from the query, like a `Cow<'a, str>` field.
+    ///
+    /// # Errors
+    /// Returns an error if the params do not match the type, like a missing field or a value that is not a number.
+    pub fn deserialize_into<T>(self) -> Result<T, QuerySerdeError>
+    where
+        T: Deserialize<'a>,
+    {
+        serde_html_form::from_str(self.value()).map_err(QuerySerdeError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Query, QuerySerdeError};
+    use serde::Deserialize;
+    use std::borrow::Cow;
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Search<'a> {
+        #[serde(borrow)]
+        q: Cow<'a, str>,
+        page: Option<u32>,
+        #[serde(default)]
+        tag: Vec<String>,
+    }
+
+    #[test]
+    fn deserialize_into() {
+        type Expected = Result<(&'static str, Option<u32>, &'static [&'static str]), &'static str>;
+        let test_cases: &[(&str, Expected)] = &[
+            ("?q=rust", Ok(("rust", None, &[]))),
+            ("?q=rust&page=2", Ok(("rust", Some(2), &[]))),
+            ("?q=rust+lang&page=", Ok(("rust lang", None, &[]))),
+            ("?q=a%26b%3Dc", Ok(("a&b=c", None, &[]))),
+            ("?q=%E2%9C%93&tag=a&tag=b%20c", Ok(("✓", None, &["a", "b c"]))),
+            ("?page=2&q=x&unknown=1", Ok(("x", Some(2), &[]))),
+            ("?page=2", Err("missing field `q`")),
+            ("?q=x&page=two", Err("invalid digit found in string")),
+        ];
+        for (input, expected) in test_cases {
+            let query: Query = Query::new(input).unwrap();
+            let result: Result<Search, QuerySerdeError> = query.deserialize_into();
+            match (result, expected) {
+                (Ok(search), Ok((q, page, tag))) => {
+                    assert_eq!(search.q, *q, "input={}", input);
+                    assert_eq!(search.page, *page, "input={}", input);
+                    assert_eq!(search.tag, *tag, "input={}", input);
+                }
+                (Err(error), Err(message)) => assert_eq!(error.message(), *message, "input={}", input),
+                (result, _) => panic!("input={} result={:?}", input, result),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_into_borrows() {
+        let query: Query = Query::new("?q=borrowed").unwrap();
+        let search: Search = query.deserialize_into().unwrap();
+        assert!(matches!(search.q, Cow::Borrowed("borrowed")));
+    }
+}
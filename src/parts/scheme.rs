@@ -23,6 +23,15 @@ impl Scheme<'static> {
 
     /// The `https` scheme.
     pub const HTTPS: Self = Self { scheme: "https" };
+
+    /// The `ws` scheme.
+    pub const WS: Self = Self { scheme: "ws" };
+
+    /// The `wss` scheme.
+    pub const WSS: Self = Self { scheme: "wss" };
+
+    /// The `ftp` scheme.
+    pub const FTP: Self = Self { scheme: "ftp" };
 }
 
 impl<'a> Scheme<'a> {
@@ -112,6 +121,50 @@ impl<'a> Scheme<'a> {
     }
 }
 
+impl<'a> Scheme<'a> {
+    //! Default Ports
+
+    /// The schemes with a well-known default port & their default ports.
+    const DEFAULT_PORTS: &'static [(&'static str, u16)] =
+        &[("http", 80), ("https", 443), ("ws", 80), ("wss", 443), ("ftp", 21)];
+
+    /// Gets the optional default port for the `scheme`, ignoring case.
+    ///
+    /// See [`Self::default_port`].
+    pub(crate) const fn default_port_ignore_case(scheme: &str) -> Option<u16> {
+        // The ports are scanned by hand since `Iterator::find` is not const.
+        let mut index: usize = 0;
+        while index < Self::DEFAULT_PORTS.len() {
+            let (s, port) = Self::DEFAULT_PORTS[index];
+            if s.eq_ignore_ascii_case(scheme) {
+                return Some(port);
+            }
+            index += 1;
+        }
+        None
+    }
+
+    /// Gets the optional default port.
+    ///
+    /// The `http` & `ws` schemes default to port 80, the `https` & `wss` schemes default to port 443, & the `ftp`
+    /// scheme defaults to port 21. Other schemes have no default port. The default port is elided when a URL is
+    /// normalized.
+    #[must_use]
+    pub const fn default_port(self) -> Option<u16> {
+        Self::default_port_ignore_case(self.scheme)
+    }
+
+    /// Gets the `'static` scheme with the default port that is equal to this scheme.
+    ///
+    /// Returns `None` if the scheme has no default port.
+    pub(crate) fn with_default_port(self) -> Option<Scheme<'static>> {
+        Self::DEFAULT_PORTS
+            .iter()
+            .find(|(scheme, _)| *scheme == self.scheme)
+            .map(|(scheme, _)| Scheme { scheme })
+    }
+}
+
 impl<'a> PartialEq<str> for Scheme<'a> {
     fn eq(&self, other: &str) -> bool {
         self.scheme == other
@@ -181,6 +234,36 @@ mod tests {
     fn constants() {
         assert_eq!(Scheme::HTTP, "http");
         assert_eq!(Scheme::HTTPS, "https");
+        assert_eq!(Scheme::WS, "ws");
+        assert_eq!(Scheme::WSS, "wss");
+        assert_eq!(Scheme::FTP, "ftp");
+    }
+
+    #[test]
+    fn default_port() {
+        let test_cases: &[(&str, Option<u16>)] = &[
+            ("http", Some(80)),
+            ("https", Some(443)),
+            ("ws", Some(80)),
+            ("wss", Some(443)),
+            ("ftp", Some(21)),
+            ("ftps", None),
+            ("s", None),
+        ];
+        for (scheme, expected) in test_cases {
+            let result: Option<u16> = Scheme::try_from(*scheme).unwrap().default_port();
+            assert_eq!(result, *expected, "scheme={}", scheme);
+
+            // The parser sees the scheme before it is lowercased.
+            let result: Option<u16> = Scheme::default_port_ignore_case(&scheme.to_ascii_uppercase());
+            assert_eq!(result, *expected, "scheme={}", scheme);
+
+            let result: Option<&str> = Scheme::try_from(*scheme)
+                .unwrap()
+                .with_default_port()
+                .map(Scheme::as_str);
+            assert_eq!(result, expected.map(|_| *scheme), "scheme={}", scheme);
+        }
     }
 
     #[test]
@@ -0,0 +1,259 @@
+use crate::Error;
+use crate::Error::InvalidUserInfo;
+use crate::parse;
+use crate::percent;
+use std::borrow::{Borrow, Cow};
+use std::fmt::{Debug, Display, Formatter};
+
+/// A web-based URL user info.
+///
+/// # RFC 3986
+/// The user info string excludes the '@' delimiter, like the RFC `userinfo` production. The user info can be empty, as
+/// in `http://@host/`. The `user:password` form is deprecated by the RFC but is still common for proxy URLs & basic
+/// auth endpoints. <https://www.rfc-editor.org/rfc/rfc3986#section-3.2.1>
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct UserInfo<'a> {
+    user_info: &'a str,
+}
+
+impl<'a> UserInfo<'a> {
+    //! Validation
+
+    /// Checks if the `user_info` is valid.
+    ///
+    /// A user info string can be empty & excludes the '@' delimiter. The valid user info format is defined by
+    /// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.2.1).
+    #[must_use]
+    pub const fn is_valid(user_info: &str) -> bool {
+        parse::is_valid_chars(user_info.as_bytes(), "@/?")
+    }
+}
+
+impl<'a> UserInfo<'a> {
+    //! Construction
+
+    /// Creates a new user info.
+    pub const fn new(user_info: &'a str) -> Result<Self, Error> {
+        if Self::is_valid(user_info) {
+            Ok(Self { user_info })
+        } else {
+            Err(InvalidUserInfo)
+        }
+    }
+
+    /// Creates a new user info.
+    ///
+    /// # Safety
+    /// The `user_info` must be valid.
+    pub const unsafe fn new_unchecked(user_info: &'a str) -> Self {
+        debug_assert!(Self::is_valid(user_info));
+
+        Self { user_info }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for UserInfo<'a> {
+    type Error = Error;
+
+    fn try_from(user_info: &'a str) -> Result<Self, Self::Error> {
+        Self::new(user_info)
+    }
+}
+
+impl<'a> UserInfo<'a> {
+    //! Properties
+
+    /// Gets the user info string. (will not contain the '@' suffix)
+    #[must_use]
+    pub const fn as_str(self) -> &'a str {
+        self.user_info
+    }
+
+    /// Gets the username. (the user info before the first ':')
+    #[must_use]
+    pub fn username(self) -> &'a str {
+        match self.user_info.find(':') {
+            Some(colon) => &self.user_info[..colon],
+            None => self.user_info,
+        }
+    }
+
+    /// Gets the optional password. (the user info after the first ':')
+    ///
+    /// The password is `None` when there is no ':' & empty when nothing follows it.
+    #[must_use]
+    pub fn password(self) -> Option<&'a str> {
+        self.user_info.find(':').map(|colon| &self.user_info[colon + 1..])
+    }
+}
+
+impl<'a> UserInfo<'a> {
+    //! Decoding
+
+    /// Gets the percent-decoded username.
+    ///
+    /// The username is borrowed when there is nothing to decode.
+    ///
+    /// # Errors
+    /// Returns `InvalidUtf8` when the decoded username is not valid UTF-8.
+    pub fn decoded_username(self) -> Result<Cow<'a, str>, Error> {
+        percent::decode(self.username())
+    }
+
+    /// Gets the percent-decoded optional password. (see [`Self::decoded_username`])
+    pub fn decoded_password(self) -> Result<Option<Cow<'a, str>>, Error> {
+        self.password().map(percent::decode).transpose()
+    }
+}
+
+impl<'a> PartialEq<str> for UserInfo<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.user_info == other
+    }
+}
+
+impl<'a> PartialEq<UserInfo<'a>> for str {
+    fn eq(&self, other: &UserInfo<'a>) -> bool {
+        self == other.user_info
+    }
+}
+
+impl<'a> PartialEq<&str> for UserInfo<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.user_info == *other
+    }
+}
+
+impl<'a> PartialEq<UserInfo<'a>> for &str {
+    fn eq(&self, other: &UserInfo<'a>) -> bool {
+        *self == other.user_info
+    }
+}
+
+impl<'a> PartialEq<String> for UserInfo<'a> {
+    fn eq(&self, other: &String) -> bool {
+        self.user_info == other.as_str()
+    }
+}
+
+impl<'a> PartialEq<UserInfo<'a>> for String {
+    fn eq(&self, other: &UserInfo<'a>) -> bool {
+        self.as_str() == other.user_info
+    }
+}
+
+impl<'a> AsRef<str> for UserInfo<'a> {
+    fn as_ref(&self) -> &str {
+        self.user_info
+    }
+}
+
+impl<'a> Borrow<str> for UserInfo<'a> {
+    fn borrow(&self) -> &str {
+        self.user_info
+    }
+}
+
+impl<'a> Debug for UserInfo<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for UserInfo<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.user_info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::InvalidUtf8;
+    use crate::{Error, UserInfo};
+
+    #[test]
+    fn is_valid() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", true),
+            ("user", true),
+            ("user:pass", true),
+            (":", true),
+            ("a:b:c", true),
+            ("azAZ09-._~!$&'()*+,;=:", true),
+            // A '%' char begins a percent-encoded octet & must be followed by two hex digits.
+            ("user%40example.com:p%3Aw", true),
+            ("%", false),
+            ("%zz", false),
+            // The delimiters of the authority & the path are not user info chars.
+            ("a@b", false),
+            ("a/b", false),
+            ("a?b", false),
+            ("a#b", false),
+            ("a[b]", false),
+            ("a b", false),
+            ("\u{4f60}", false),
+        ];
+        for (user_info, expected) in test_cases {
+            let result: bool = UserInfo::is_valid(user_info);
+            assert_eq!(result, *expected, "user_info={}", user_info);
+        }
+    }
+
+    #[test]
+    fn username_password() {
+        let test_cases: &[(&str, &str, Option<&str>)] = &[
+            ("", "", None),
+            ("user", "user", None),
+            ("user:", "user", Some("")),
+            (":pass", "", Some("pass")),
+            ("user:pass", "user", Some("pass")),
+            // Only the first ':' separates, so the password may hold more.
+            ("user:pa:ss", "user", Some("pa:ss")),
+            // The escapes are not decoded.
+            ("us%3Aer:pass", "us%3Aer", Some("pass")),
+        ];
+        for (user_info, username, password) in test_cases {
+            let user_info: UserInfo = UserInfo::try_from(*user_info).unwrap();
+            assert_eq!(user_info.username(), *username, "user_info={}", user_info);
+            assert_eq!(user_info.password(), *password, "user_info={}", user_info);
+        }
+    }
+
+    /// The username & optional password.
+    type UserInfoParts<'a> = (&'a str, Option<&'a str>);
+
+    #[test]
+    fn decoded() {
+        let test_cases: &[(&str, Result<UserInfoParts, Error>)] = &[
+            ("user", Ok(("user", None))),
+            ("user%40example.com:p%3Aw", Ok(("user@example.com", Some("p:w")))),
+            ("a+b:c+d", Ok(("a+b", Some("c+d")))),
+            ("%FF:pass", Err(InvalidUtf8)),
+            ("user:%FF", Err(InvalidUtf8)),
+        ];
+        for (user_info, expected) in test_cases {
+            let user_info: UserInfo = UserInfo::try_from(*user_info).unwrap();
+            match expected {
+                Ok((username, password)) => {
+                    assert_eq!(
+                        user_info.decoded_username().unwrap(),
+                        *username,
+                        "user_info={}",
+                        user_info
+                    );
+                    assert_eq!(
+                        user_info.decoded_password().unwrap().as_deref(),
+                        *password,
+                        "user_info={}",
+                        user_info
+                    );
+                }
+                Err(expected) => {
+                    let result: Result<_, Error> = user_info.decoded_username().and(user_info.decoded_password());
+                    assert_eq!(result.unwrap_err(), *expected, "user_info={}", user_info);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Error;
+use crate::Error::{InvalidPercentEncoding, InvalidUtf8};
+use std::borrow::Cow;
+
+/// Percent-decodes the string `s` into its bytes.
+///
+/// The bytes are borrowed when there is nothing to decode.
+///
+/// # Errors
+/// Returns `InvalidPercentEncoding` when a '%' char is not followed by two hex digits.
+pub fn decode_bytes(s: &str) -> Result<Cow<'_, [u8]>, Error> {
+    decode_with(s, false)
+}
+
+/// Percent-decodes the string `s`.
+///
+/// The string is borrowed when there is nothing to decode.
+///
+/// # Errors
+/// Returns `InvalidPercentEncoding` when a '%' char is not followed by two hex digits & `InvalidUtf8` when the decoded
+/// bytes are not valid UTF-8.
+///
+/// # Example
+/// `"a%20b%C3%A9"` -> `"a b\u{e9}"`
+pub fn decode(s: &str) -> Result<Cow<'_, str>, Error> {
+    into_str(s, decode_with(s, false)?)
+}
+
+/// Percent-decodes the `application/x-www-form-urlencoded` string `s`. (a '+' char decodes to a space)
+///
+/// The string is borrowed when there is nothing to decode.
+///
+/// # Errors
+/// See [`decode`].
+///
+/// # WHATWG
+/// <https://url.spec.whatwg.org/#application/x-www-form-urlencoded>
+pub fn decode_form(s: &str) -> Result<Cow<'_, str>, Error> {
+    into_str(s, decode_with(s, true)?)
+}
+
+/// Percent-decodes the string `s`, decoding a '+' char to a space when `plus_as_space` is set.
+fn decode_with(s: &str, plus_as_space: bool) -> Result<Cow<'_, [u8]>, Error> {
+    let bytes: &[u8] = s.as_bytes();
+    if !bytes.iter().any(|c| *c == b'%' || (plus_as_space && *c == b'+')) {
+        return Ok(Cow::Borrowed(bytes));
+    }
+
+    // Every escape shrinks by 2 bytes, so the decoded bytes are never longer than the string.
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut index: usize = 0;
+    while index < bytes.len() {
+        let c: u8 = bytes[index];
+        if c == b'%' {
+            match (hex_value(bytes.get(index + 1)), hex_value(bytes.get(index + 2))) {
+                (Some(high), Some(low)) => decoded.push((high << 4) | low),
+                _ => return Err(InvalidPercentEncoding),
+            }
+            index += 3;
+        } else {
+            decoded.push(if plus_as_space && c == b'+' { b' ' } else { c });
+            index += 1;
+        }
+    }
+    Ok(Cow::Owned(decoded))
+}
+
+/// Gets the value of the optional hex digit `c`.
+fn hex_value(c: Option<&u8>) -> Option<u8> {
+    (*c? as char).to_digit(16).map(|d| d as u8)
+}
+
+/// Converts the `decoded` bytes of the string `s` into a string.
+fn into_str<'a>(s: &'a str, decoded: Cow<'a, [u8]>) -> Result<Cow<'a, str>, Error> {
+    match decoded {
+        // The bytes are only borrowed when nothing was decoded, so they are the string itself.
+        Cow::Borrowed(_) => Ok(Cow::Borrowed(s)),
+        Cow::Owned(decoded) => String::from_utf8(decoded).map(Cow::Owned).map_err(|_| InvalidUtf8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use crate::Error::{InvalidPercentEncoding, InvalidUtf8};
+    use crate::percent::{decode, decode_bytes, decode_form};
+    use std::borrow::Cow;
+
+    #[test]
+    fn fn_decode() {
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("", Ok("")),
+            ("abc", Ok("abc")),
+            ("a+b", Ok("a+b")),
+            ("a%20b", Ok("a b")),
+            ("%2f%2F", Ok("//")),
+            ("100%25", Ok("100%")),
+            ("%25zz", Ok("%zz")),
+            ("%C3%A9", Ok("\u{e9}")),
+            ("%e4%bd%a0", Ok("\u{4f60}")),
+            ("%", Err(InvalidPercentEncoding)),
+            ("%2", Err(InvalidPercentEncoding)),
+            ("%zz", Err(InvalidPercentEncoding)),
+            ("a%2gb", Err(InvalidPercentEncoding)),
+            // A lone continuation byte & a truncated sequence are not UTF-8.
+            ("%80", Err(InvalidUtf8)),
+            ("%C3", Err(InvalidUtf8)),
+            ("%FF%FE", Err(InvalidUtf8)),
+        ];
+        for (s, expected) in test_cases {
+            let result: Result<Cow<str>, Error> = decode(s);
+            assert_eq!(result.as_deref().map_err(|e| *e), *expected, "s={}", s);
+            // The string is borrowed exactly when nothing is decoded.
+            if let Ok(result) = result {
+                assert_eq!(matches!(result, Cow::Borrowed(_)), !s.contains('%'), "s={}", s);
+            }
+        }
+    }
+
+    #[test]
+    fn fn_decode_bytes() {
+        let test_cases: &[(&str, Result<&[u8], Error>)] = &[
+            ("abc", Ok(b"abc")),
+            ("%80%FF", Ok(b"\x80\xff")),
+            ("a%00b", Ok(b"a\x00b")),
+            ("%8", Err(InvalidPercentEncoding)),
+        ];
+        for (s, expected) in test_cases {
+            let result: Result<Cow<[u8]>, Error> = decode_bytes(s);
+            assert_eq!(result.as_deref().map_err(|e| *e), *expected, "s={}", s);
+        }
+    }
+
+    #[test]
+    fn fn_decode_form() {
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("abc", Ok("abc")),
+            ("a+b", Ok("a b")),
+            ("a%2Bb", Ok("a+b")),
+            ("a+%20b", Ok("a  b")),
+            ("%", Err(InvalidPercentEncoding)),
+            ("+%80", Err(InvalidUtf8)),
+        ];
+        for (s, expected) in test_cases {
+            let result: Result<Cow<str>, Error> = decode_form(s);
+            assert_eq!(result.as_deref().map_err(|e| *e), *expected, "s={}", s);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::percent::EncodeSet;
+use std::borrow::Cow;
+
+/// The uppercase hex digits. The RFC 3986 normal form uses uppercase hex digits.
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Percent-encodes the string `s` with the encode `set`.
+///
+/// The string is borrowed when no char needs to be encoded. Every UTF-8 byte of a non-ASCII char is encoded.
+///
+/// # Example
+/// `("a b&c", EncodeSet::QUERY_VALUE)` -> `"a%20b%26c"`
+#[must_use]
+pub fn encode(s: &str, set: EncodeSet) -> Cow<'_, str> {
+    if s.bytes().any(|c| set.should_encode(c)) {
+        let mut encoded: String = String::with_capacity(encoded_len(s, set));
+        encode_to(s, set, &mut encoded);
+        Cow::Owned(encoded)
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+/// Percent-encodes the string `s` with the encode `set` & appends it to the `target`.
+pub fn encode_to(s: &str, set: EncodeSet, target: &mut String) {
+    for c in s.bytes() {
+        if set.should_encode(c) {
+            target.push('%');
+            target.push(HEX[(c >> 4) as usize] as char);
+            target.push(HEX[(c & 0xF) as usize] as char);
+        } else {
+            target.push(c as char);
+        }
+    }
+}
+
+/// Gets the length of the string `s` once percent-encoded with the encode `set`.
+#[must_use]
+pub fn encoded_len(s: &str, set: EncodeSet) -> usize {
+    s.bytes().map(|c| if set.should_encode(c) { 3 } else { 1 }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::percent::{EncodeSet, encode, encoded_len};
+    use std::borrow::Cow;
+
+    #[test]
+    fn fn_encode() {
+        let test_cases: &[(&str, EncodeSet, &str)] = &[
+            ("", EncodeSet::PATH_SEGMENT, ""),
+            ("abc", EncodeSet::PATH_SEGMENT, "abc"),
+            ("a/b?c", EncodeSet::PATH_SEGMENT, "a%2Fb%3Fc"),
+            ("a/b?c", EncodeSet::PATH, "a/b%3Fc"),
+            ("a b", EncodeSet::FRAGMENT, "a%20b"),
+            ("100%", EncodeSet::FRAGMENT, "100%25"),
+            ("a=b&c+d", EncodeSet::QUERY_NAME, "a%3Db%26c%2Bd"),
+            ("a=b&c+d", EncodeSet::QUERY_VALUE, "a=b%26c%2Bd"),
+            ("user@example.com", EncodeSet::USER_INFO, "user%40example.com"),
+            ("p:w", EncodeSet::USER_INFO, "p%3Aw"),
+            // Every UTF-8 byte of a non-ASCII char is encoded.
+            ("\u{e9}", EncodeSet::FRAGMENT, "%C3%A9"),
+            ("\u{4f60}", EncodeSet::FRAGMENT, "%E4%BD%A0"),
+        ];
+        for (s, set, expected) in test_cases {
+            let result: Cow<str> = encode(s, *set);
+            assert_eq!(result, *expected, "s={}", s);
+            assert_eq!(encoded_len(s, *set), expected.len(), "s={}", s);
+            // The string is borrowed exactly when nothing is encoded.
+            assert_eq!(matches!(result, Cow::Borrowed(_)), *s == *expected, "s={}", s);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::parse;
+
+/// A set of the chars to percent-encode for a URL component.
+///
+/// The encoded chars are the '%' char & every char the component does not accept. The accepted chars are the RFC 3986
+/// chars of the component, so an encoded string is always valid for it.
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct EncodeSet {
+    exclude: &'static str,
+}
+
+impl EncodeSet {
+    //! Constants
+
+    /// The set for a path segment. (the '/' & '?' chars are encoded)
+    pub const PATH_SEGMENT: Self = Self { exclude: "/?" };
+
+    /// The set for a path. (the '?' char is encoded)
+    pub const PATH: Self = Self { exclude: "?" };
+
+    /// The set for a query parameter name. (the '&', '=', & '+' chars are encoded)
+    ///
+    /// The '+' char is encoded since `application/x-www-form-urlencoded` decodes it as a space.
+    pub const QUERY_NAME: Self = Self { exclude: "&=+" };
+
+    /// The set for a query parameter value. (the '&' & '+' chars are encoded)
+    ///
+    /// The '=' char is not encoded since only the first one separates the name from the value.
+    pub const QUERY_VALUE: Self = Self { exclude: "&+" };
+
+    /// The set for a fragment.
+    pub const FRAGMENT: Self = Self { exclude: "" };
+
+    /// The set for a user info username or password. (the '@', '/', '?', & ':' chars are encoded)
+    ///
+    /// The ':' char is encoded since the first one separates the username from the password.
+    pub const USER_INFO: Self = Self { exclude: "@/?:" };
+}
+
+impl EncodeSet {
+    //! Properties
+
+    /// Checks if the char `c` must be percent-encoded.
+    #[must_use]
+    pub const fn should_encode(self, c: u8) -> bool {
+        c == b'%' || !parse::is_valid_char(c, self.exclude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Fragment;
+    use crate::Param;
+    use crate::UserInfo;
+    use crate::percent::EncodeSet;
+
+    #[test]
+    fn should_encode() {
+        let test_cases: &[(EncodeSet, &[u8], &[u8])] = &[
+            (
+                EncodeSet::PATH_SEGMENT,
+                b"az09-._~!$&'()*+,;=:@",
+                b"%/? #[]\"<>\\^`{|}\x00\x7f\x80\xff",
+            ),
+            (EncodeSet::PATH, b"az09-._~!$&'()*+,;=:@/", b"%? #"),
+            (EncodeSet::QUERY_NAME, b"az09-._~!$'()*,;:@/?", b"%&=+ #"),
+            (EncodeSet::QUERY_VALUE, b"az09-._~!$'()*,;=:@/?", b"%&+ #"),
+            (EncodeSet::FRAGMENT, b"az09-._~!$&'()*+,;=:@/?", b"% #"),
+            (EncodeSet::USER_INFO, b"az09-._~!$&'()*+,;=", b"%@/?: #"),
+        ];
+        for (set, keep, encode) in test_cases {
+            for c in keep.iter() {
+                assert!(!set.should_encode(*c), "set={:?} c={}", set, *c as char);
+            }
+            for c in encode.iter() {
+                assert!(set.should_encode(*c), "set={:?} c={}", set, *c as char);
+            }
+        }
+    }
+
+    /// Every char that is kept must be valid for the component, so an encoded string is always valid.
+    #[test]
+    fn should_encode_matches_components() {
+        for c in 0..128u8 {
+            let s: String = (c as char).to_string();
+            if !EncodeSet::QUERY_NAME.should_encode(c) {
+                assert!(Param::is_valid_name(s.as_str()), "c={}", c);
+            }
+            if !EncodeSet::QUERY_VALUE.should_encode(c) {
+                assert!(Param::is_valid_value(s.as_str()), "c={}", c);
+            }
+            if !EncodeSet::FRAGMENT.should_encode(c) {
+                assert!(Fragment::is_valid(format!("#{}", s).as_str()), "c={}", c);
+            }
+            if !EncodeSet::USER_INFO.should_encode(c) {
+                assert!(UserInfo::is_valid(s.as_str()), "c={}", c);
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Percent-encoding for the URL components.
+//!
+//! # RFC 3986
+//! A percent-encoded octet is a '%' char followed by two hex digits. The encode sets match the chars the components
+//! accept, so an encoded string is always a valid component.
+//! <https://www.rfc-editor.org/rfc/rfc3986#section-2.1>
+
+pub use decode::*;
+pub use encode::*;
+pub use encode_set::*;
+
+mod decode;
+mod encode;
+mod encode_set;
@@ -0,0 +1,140 @@
+//! The Public Suffix List `.dat` file format.
+//!
+//! This module has no crate dependencies since the build script includes it to compile the embedded list.
+
+use std::collections::BTreeMap;
+
+/// The flag for a rule that matches the suffix. (`co.uk`)
+pub const RULE: u8 = 1;
+
+/// The flag for a wildcard rule that matches any label followed by the suffix. (`*.ck`)
+pub const WILDCARD: u8 = 2;
+
+/// The flag for an exception rule that matches the suffix & overrides a wildcard rule. (`!www.ck`)
+pub const EXCEPTION: u8 = 4;
+
+/// Parses the rules of the `.dat` file contents `dat`.
+///
+/// The rules are keyed by their suffix in its lowercase A-label form & sorted by it. The flags of the rules with the
+/// same suffix are combined. The comments & blank lines are skipped, as are the rules that are not valid domains, since
+/// they could never match a host.
+pub fn parse_rules(dat: &str) -> Vec<(String, u8)> {
+    let mut rules: BTreeMap<String, u8> = BTreeMap::new();
+    for line in dat.lines() {
+        let rule: &str = line.split_whitespace().next().unwrap_or_default();
+        if rule.is_empty() || rule.starts_with("//") {
+            continue;
+        }
+        let (suffix, flag) = if let Some(suffix) = rule.strip_prefix('!') {
+            (suffix, EXCEPTION)
+        } else if let Some(suffix) = rule.strip_prefix("*.") {
+            (suffix, WILDCARD)
+        } else {
+            (rule, RULE)
+        };
+        if let Some(suffix) = to_ascii(suffix) {
+            *rules.entry(suffix).or_default() |= flag;
+        }
+    }
+    rules.into_iter().collect()
+}
+
+/// Converts the rule `suffix` to its lowercase A-label form.
+///
+/// The list is in Unicode normalization form C, so only the Punycode conversion is needed. Returns `None` if a label is
+/// empty or has a char that is not a letter, digit, or hyphen after the conversion.
+fn to_ascii(suffix: &str) -> Option<String> {
+    let mut ascii: String = String::with_capacity(suffix.len());
+    for label in suffix.split('.') {
+        if !ascii.is_empty() {
+            ascii.push('.');
+        }
+        if label.is_ascii() {
+            ascii.push_str(&label.to_ascii_lowercase());
+        } else {
+            ascii.push_str("xn--");
+            ascii.push_str(&punycode(&label.to_lowercase())?);
+        }
+    }
+    let valid: bool = ascii
+        .split('.')
+        .all(|label| !label.is_empty() && label.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-'));
+    valid.then_some(ascii)
+}
+
+/// The Punycode parameters.
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+/// Encodes the `label` with Punycode.
+///
+/// # RFC 3492
+/// <https://www.rfc-editor.org/rfc/rfc3492#section-6.3>
+fn punycode(label: &str) -> Option<String> {
+    let input: Vec<u32> = label.chars().map(u32::from).collect();
+    let mut output: String = label.chars().filter(char::is_ascii).collect();
+    let basic: u32 = output.len() as u32;
+    if basic > 0 {
+        output.push('-');
+    }
+
+    let mut n: u32 = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias: u32 = INITIAL_BIAS;
+    let mut handled: u32 = basic;
+    while (handled as usize) < input.len() {
+        let m: u32 = input.iter().copied().filter(|c| *c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+        for c in input.iter().copied() {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            } else if c == n {
+                let mut q: u32 = delta;
+                let mut k: u32 = BASE;
+                loop {
+                    let t: u32 = k.saturating_sub(bias).clamp(T_MIN, T_MAX);
+                    if q < t {
+                        break;
+                    }
+                    output.push(punycode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(punycode_digit(q));
+                bias = punycode_adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Some(output)
+}
+
+/// Gets the Punycode char for the `digit`.
+fn punycode_digit(digit: u32) -> char {
+    if digit < 26 {
+        (b'a' + digit as u8) as char
+    } else {
+        (b'0' + (digit - 26) as u8) as char
+    }
+}
+
+/// Adapts the Punycode bias.
+fn punycode_adapt(delta: u32, handled: u32, first: bool) -> u32 {
+    let mut delta: u32 = if first { delta / DAMP } else { delta / 2 };
+    delta += delta / handled;
+    let mut k: u32 = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + ((BASE - T_MIN + 1) * delta) / (delta + SKEW)
+}
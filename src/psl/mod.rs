@@ -0,0 +1,8 @@
+pub use public_suffix_list::*;
+pub use site::*;
+pub use tuple_site::*;
+
+mod dat;
+mod public_suffix_list;
+mod site;
+mod tuple_site;
@@ -0,0 +1,218 @@
+use crate::psl::dat::{EXCEPTION, RULE, WILDCARD, parse_rules};
+use address::DomainRef;
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+
+include!(concat!(env!("OUT_DIR"), "/public_suffix_rules.rs"));
+
+/// A Public Suffix List, which is the list of the suffixes under which anyone can register a domain.
+///
+/// The [`Self::EMBEDDED`] list is compiled into the crate when it is built. A newer list can be loaded from a `.dat`
+/// file at runtime with [`Self::load`] or [`Self::parse`]. The rules of both the ICANN & the private sections are used,
+/// as browsers do.
+///
+/// # Public Suffix List
+/// <https://publicsuffix.org/list/>
+#[derive(Clone)]
+pub struct PublicSuffixList {
+    rules: Rules,
+}
+
+/// The sorted rules of a list.
+#[derive(Clone)]
+enum Rules {
+    Embedded(&'static [(&'static str, u8)]),
+    Loaded(Vec<(String, u8)>),
+}
+
+impl PublicSuffixList {
+    //! Construction
+
+    /// The list embedded in the crate.
+    pub const EMBEDDED: Self = Self {
+        rules: Rules::Embedded(RULES),
+    };
+
+    /// Parses the list from the `.dat` file contents `dat`.
+    ///
+    /// The comments & blank lines are skipped, as are the rules that are not valid domains.
+    #[must_use]
+    pub fn parse(dat: &str) -> Self {
+        Self {
+            rules: Rules::Loaded(parse_rules(dat)),
+        }
+    }
+
+    /// Loads the list from the `.dat` file at the `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or has no rules.
+    pub fn load<P>(path: P) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let list: Self = Self::parse(&std::fs::read_to_string(path)?);
+        if list.is_empty() {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "the file has no public suffix rules",
+            ))
+        } else {
+            Ok(list)
+        }
+    }
+}
+
+impl Default for PublicSuffixList {
+    fn default() -> Self {
+        Self::EMBEDDED
+    }
+}
+
+impl PublicSuffixList {
+    //! Properties
+
+    /// Gets the number of rules. (the rules with the same suffix are counted once)
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.rules {
+            Rules::Embedded(rules) => rules.len(),
+            Rules::Loaded(rules) => rules.len(),
+        }
+    }
+
+    /// Checks if the list has no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the rule flags for the `suffix`. (0 if there are no rules for it)
+    fn flags(&self, suffix: &str) -> u8 {
+        let flags: Result<u8, usize> = match &self.rules {
+            Rules::Embedded(rules) => rules
+                .binary_search_by(|(rule, _)| (*rule).cmp(suffix))
+                .map(|index| rules[index].1),
+            Rules::Loaded(rules) => rules
+                .binary_search_by(|(rule, _)| rule.as_str().cmp(suffix))
+                .map(|index| rules[index].1),
+        };
+        flags.unwrap_or_default()
+    }
+}
+
+impl PublicSuffixList {
+    //! Lookup
+
+    /// Gets the public suffix of the `domain`. (`www.example.co.uk` -> `co.uk`)
+    ///
+    /// A domain with no matching rule has its last label as its public suffix, so every domain has one. The public
+    /// suffix may be the whole domain.
+    ///
+    /// # Public Suffix List
+    /// <https://github.com/publicsuffix/list/wiki/Format#algorithm>
+    pub fn public_suffix<'a>(&self, domain: DomainRef<'a>) -> DomainRef<'a> {
+        let name: &'a str = domain.name();
+        let suffix: &'a str = &name[self.public_suffix_start(name)..];
+        unsafe { DomainRef::new_unchecked(suffix) }
+    }
+
+    /// Gets the registrable domain of the `domain`, which is its public suffix & the label before it.
+    /// (`www.example.co.uk` -> `example.co.uk`)
+    ///
+    /// Returns `None` if the domain is a public suffix.
+    #[must_use]
+    pub fn registrable_domain<'a>(&self, domain: DomainRef<'a>) -> Option<DomainRef<'a>> {
+        let name: &'a str = domain.name();
+        let suffix_start: usize = self.public_suffix_start(name);
+        if suffix_start == 0 {
+            None
+        } else {
+            let start: usize = name[..suffix_start - 1]
+                .rfind('.')
+                .map(|dot| dot + 1)
+                .unwrap_or_default();
+            Some(unsafe { DomainRef::new_unchecked(&name[start..]) })
+        }
+    }
+
+    /// Gets the start of the public suffix of the domain `name`.
+    ///
+    /// The suffixes are checked from the shortest to the longest, so the last match is the longest. An exception rule
+    /// prevails over the other rules & its public suffix is the rule without its first label.
+    fn public_suffix_start(&self, name: &str) -> usize {
+        let label_starts: Vec<usize> = std::iter::once(0)
+            .chain(name.match_indices('.').map(|(dot, _)| dot + 1))
+            .collect();
+        let mut suffix_start: usize = *label_starts.last().unwrap();
+        for (index, start) in label_starts.iter().copied().enumerate().rev() {
+            let flags: u8 = self.flags(&name[start..]);
+            if flags & EXCEPTION != 0 {
+                return label_starts.get(index + 1).copied().unwrap_or(start);
+            }
+            if flags & RULE != 0 {
+                suffix_start = start;
+            }
+            if flags & WILDCARD != 0 && index > 0 {
+                suffix_start = label_starts[index - 1];
+            }
+        }
+        suffix_start
+    }
+}
+
+impl Debug for PublicSuffixList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PublicSuffixList").field("rules", &self.len()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PublicSuffixList;
+    use address::DomainRef;
+
+    /// A list with each kind of rule.
+    const DAT: &str = "// comment\n\ncom\nco.uk\nuk\n*.ck\n!www.ck\nblogspot.co.uk extra\n公司.cn\ninvalid_rule\n";
+
+    #[test]
+    fn parse() {
+        let list: PublicSuffixList = PublicSuffixList::parse(DAT);
+        assert_eq!(list.len(), 7);
+        assert!(!list.is_empty());
+        assert!(PublicSuffixList::parse("// only a comment\n").is_empty());
+        assert!(PublicSuffixList::EMBEDDED.len() > 9000);
+    }
+
+    #[test]
+    fn public_suffix() {
+        let test_cases: &[(&str, &str, Option<&str>)] = &[
+            ("com", "com", None),
+            ("example.com", "com", Some("example.com")),
+            ("www.example.com", "com", Some("example.com")),
+            ("example.co.uk", "co.uk", Some("example.co.uk")),
+            ("a.b.example.co.uk", "co.uk", Some("example.co.uk")),
+            (
+                "example.blogspot.co.uk",
+                "blogspot.co.uk",
+                Some("example.blogspot.co.uk"),
+            ),
+            ("uk", "uk", None),
+            ("ck", "ck", None),
+            ("example.ck", "example.ck", None),
+            ("www.example.ck", "example.ck", Some("www.example.ck")),
+            ("www.ck", "ck", Some("www.ck")),
+            ("a.www.ck", "ck", Some("www.ck")),
+            ("example.xn--55qx5d.cn", "xn--55qx5d.cn", Some("example.xn--55qx5d.cn")),
+            ("localhost", "localhost", None),
+            ("example.unlisted", "unlisted", Some("example.unlisted")),
+        ];
+        let list: PublicSuffixList = PublicSuffixList::parse(DAT);
+        for (input, suffix, registrable) in test_cases {
+            let domain: DomainRef = unsafe { DomainRef::new_unchecked(input) };
+            assert_eq!(list.public_suffix(domain), *suffix, "input={}", input);
+            let result: Option<&str> = list.registrable_domain(domain).map(DomainRef::name);
+            assert_eq!(result, *registrable, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Origin, PublicSuffixList, TupleSite};
+use address::HostRef;
+use std::fmt::{Debug, Display, Formatter};
+
+/// The site of an origin, which is the boundary browsers use for `SameSite` cookies.
+///
+/// A tuple origin has a tuple site of its scheme & its registrable domain, so `https://a.example.com` &
+/// `https://b.example.com` are the same site. The port is ignored. An opaque origin has an opaque site, which is
+/// serialized as `null`.
+///
+/// Two sites are equal when their serializations are equal, so the opaque sites are all equal to each other. Use
+/// [`Self::same_site`] for the same-site check, which an opaque site never passes.
+///
+/// # WHATWG
+/// <https://html.spec.whatwg.org/multipage/browsers.html#site>
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Site {
+    /// A scheme & host tuple.
+    Tuple(TupleSite),
+
+    /// An opaque site. (serialized as `null`)
+    Opaque,
+}
+
+impl Site {
+    //! Properties
+
+    /// Checks if the site is opaque.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        matches!(self, Self::Opaque)
+    }
+
+    /// Gets the optional tuple site. (`None` when the site is opaque)
+    #[must_use]
+    pub const fn as_tuple(&self) -> Option<&TupleSite> {
+        match self {
+            Self::Tuple(tuple) => Some(tuple),
+            Self::Opaque => None,
+        }
+    }
+}
+
+impl Site {
+    //! Comparison
+
+    /// Checks if this site is the same site as the `other` site.
+    ///
+    /// The comparison is schemeful, so the tuple sites are the same site when their schemes & hosts are equal:
+    /// `http://example.com` & `https://example.com` are not the same site. An opaque site is never the same site as
+    /// another site.
+    ///
+    /// # WHATWG
+    /// <https://html.spec.whatwg.org/multipage/browsers.html#concept-site-same-site>
+    #[must_use]
+    pub fn same_site(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Tuple(tuple), Self::Tuple(other)) => tuple == other,
+            _ => false,
+        }
+    }
+}
+
+impl PublicSuffixList {
+    //! Site
+
+    /// Gets the site of the `origin`.
+    ///
+    /// # WHATWG
+    /// <https://html.spec.whatwg.org/multipage/browsers.html#obtain-a-site>
+    #[must_use]
+    pub fn site(&self, origin: &Origin) -> Site {
+        match origin {
+            Origin::Tuple(tuple) => {
+                let host: HostRef = match tuple.host() {
+                    HostRef::Name(domain) => self.registrable_domain(domain).unwrap_or(domain).to_host_ref(),
+                    host => host,
+                };
+                Site::Tuple(TupleSite::new(tuple.scheme(), host))
+            }
+            Origin::Opaque => Site::Opaque,
+        }
+    }
+}
+
+impl Origin {
+    //! Site
+
+    /// Gets the site with the embedded Public Suffix List. (see [`PublicSuffixList::site`])
+    #[must_use]
+    pub fn site(&self) -> Site {
+        PublicSuffixList::EMBEDDED.site(self)
+    }
+}
+
+impl Debug for Site {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Site {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tuple(tuple) => Display::fmt(tuple, f),
+            Self::Opaque => f.write_str("null"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PublicSuffixList, Site, WebUrl};
+    use std::str::FromStr;
+
+    #[test]
+    fn site() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, &str)] = &[
+            ("https://www.example.com:8443/a", "https://example.com"),
+            ("https://example.co.uk/", "https://example.co.uk"),
+            ("https://a.b.example.co.uk/", "https://example.co.uk"),
+            ("http://co.uk/", "http://co.uk"),
+            ("http://localhost:3000/", "http://localhost"),
+            ("https://[::1]/", "https://[::1]"),
+            ("https://127.0.0.1/", "https://127.0.0.1"),
+            ("custom://example.com/", "null"),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(url.site().to_string(), *expected, "input={}", input);
+            assert_eq!(url.origin().site(), url.site(), "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn same_site() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, &str, bool)] = &[
+            ("https://a.example.com/", "https://b.example.com:8443/", true),
+            ("https://example.com/", "https://www.example.com/", true),
+            ("https://example.com/", "http://example.com/", false),
+            ("https://a.example.co.uk/", "https://b.example.co.uk/", true),
+            ("https://a.co.uk/", "https://b.co.uk/", false),
+            ("https://127.0.0.1/", "https://127.0.0.1:8443/", true),
+            ("custom://example.com/", "custom://example.com/", false),
+        ];
+        for (a, b, expected) in test_cases {
+            let a: Site = WebUrl::from_str(a)?.site();
+            let b: Site = WebUrl::from_str(b)?.site();
+            assert_eq!(a.same_site(&b), *expected, "a={} b={}", a, b);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn loaded_list() -> Result<(), Box<dyn std::error::Error>> {
+        let list: PublicSuffixList = PublicSuffixList::parse("com\nexample.com\n");
+        let url: WebUrl = WebUrl::from_str("https://a.example.com/")?;
+        assert_eq!(list.site(&url.origin()).to_string(), "https://a.example.com");
+        assert_eq!(url.site().to_string(), "https://example.com");
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Scheme;
+use crate::parse::CanonicalHost;
+use address::{Host, HostRef};
+use std::fmt::{Debug, Display, Formatter};
+
+/// The site of a tuple origin: its scheme & its registrable domain, or its host when it has no registrable domain.
+///
+/// # WHATWG
+/// <https://html.spec.whatwg.org/multipage/browsers.html#scheme-and-host>
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TupleSite {
+    scheme: Scheme<'static>,
+    host: Host,
+}
+
+impl TupleSite {
+    //! Construction
+
+    /// Creates a new tuple site with the `scheme` & the `host`.
+    pub(crate) fn new(scheme: Scheme<'static>, host: HostRef) -> Self {
+        Self {
+            scheme,
+            host: host.into(),
+        }
+    }
+}
+
+impl TupleSite {
+    //! Properties
+
+    /// Gets the scheme.
+    pub const fn scheme(&self) -> Scheme<'static> {
+        self.scheme
+    }
+
+    /// Gets the host reference. (the registrable domain, the public suffix domain, or the IP address)
+    pub fn host(&self) -> HostRef<'_> {
+        HostRef::from(&self.host)
+    }
+}
+
+impl Debug for TupleSite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for TupleSite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}://", self.scheme)?;
+        match &self.host {
+            Host::Name(domain) => f.write_str(domain.name()),
+            Host::Address(ip) => f.write_str(CanonicalHost::new(*ip).as_str()),
+        }
+    }
+}
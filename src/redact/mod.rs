@@ -0,0 +1,5 @@
+pub use redacted::*;
+pub use redaction_policy::*;
+
+mod redacted;
+mod redaction_policy;
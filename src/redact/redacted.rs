@@ -0,0 +1,96 @@
+use crate::{PieceIterator, RedactionPolicy, WebUrl};
+use std::fmt::{Debug, Display, Formatter};
+
+/// A [`WebUrl`] displayed with its secrets masked. (see [`WebUrl::redacted`])
+#[derive(Copy, Clone)]
+pub struct Redacted<'a> {
+    url: &'a WebUrl,
+    policy: RedactionPolicy,
+}
+
+impl<'a> Redacted<'a> {
+    //! Construction
+
+    /// Creates a new redacted URL.
+    pub(crate) const fn new(url: &'a WebUrl, policy: RedactionPolicy) -> Self {
+        Self { url, policy }
+    }
+}
+
+impl Redacted<'_> {
+    //! Display
+
+    /// Writes the params of the query or fragment `s`, masking the values of the sensitive params.
+    ///
+    /// The `s` starts with its '?' or '#' lead char. A fragment that is not made of params has no sensitive names, so
+    /// it is written as it is.
+    fn write_params(&self, f: &mut Formatter<'_>, s: &str) -> std::fmt::Result {
+        f.write_str(&s[..1])?;
+        for (index, param) in PieceIterator::new(s, b'&').enumerate() {
+            if index > 0 {
+                f.write_str("&")?;
+            }
+            match param.split_once('=') {
+                Some((name, _)) if self.policy.is_sensitive(name) => {
+                    f.write_str(name)?;
+                    f.write_str("=")?;
+                    f.write_str(self.policy.mask())?;
+                }
+                _ => f.write_str(param)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Redacted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let url: &str = self.url.as_str();
+        let mut start: usize = self.url.scheme().as_str().len() + 3;
+        f.write_str(&url[..start])?;
+
+        if let Some(user_info) = self.url.user_info() {
+            match user_info.password() {
+                Some(_) if self.policy.redacts_password() => {
+                    f.write_str(user_info.username())?;
+                    f.write_str(":")?;
+                    f.write_str(self.policy.mask())?;
+                }
+                _ => f.write_str(user_info.as_str())?,
+            }
+            f.write_str("@")?;
+            start += user_info.as_str().len() + 1;
+        }
+
+        let query: &str = self.url.query().map(|query| query.as_str()).unwrap_or_default();
+        let fragment: &str = self
+            .url
+            .fragment()
+            .map(|fragment| fragment.as_str())
+            .unwrap_or_default();
+        f.write_str(&url[start..(url.len() - query.len() - fragment.len())])?;
+        if !query.is_empty() {
+            self.write_params(f, query)?;
+        }
+        if !fragment.is_empty() {
+            // A hash-route fragment, like `#/callback?token=x`, has its params after its first '?'. Any other fragment
+            // is masked as params, so a '?' in a param value does not expose the params before it.
+            if fragment.starts_with("#/") || fragment.starts_with("#!/") {
+                let (route, params) = fragment.split_at(fragment.find('?').unwrap_or(fragment.len()));
+                f.write_str(route)?;
+                if !params.is_empty() {
+                    self.write_params(f, params)?;
+                }
+            } else {
+                self.write_params(f, fragment)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::percent;
+use std::borrow::Cow;
+
+/// A policy for the secrets masked by [`WebUrl::redacted`](crate::WebUrl::redacted).
+///
+/// The [`Self::DEFAULT`] policy masks the values of the [`Self::SENSITIVE_NAMES`] params in the query & fragment & the
+/// user info password with `REDACTED`. It is configured with the `with_` methods, like the
+/// [`Normalizer`](crate::Normalizer).
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct RedactionPolicy {
+    names: &'static [&'static str],
+    mask: &'static str,
+    redact_password: bool,
+}
+
+impl RedactionPolicy {
+    //! Constants
+
+    /// The default policy. (see [`RedactionPolicy`])
+    pub const DEFAULT: Self = Self {
+        names: Self::SENSITIVE_NAMES,
+        mask: "REDACTED",
+        redact_password: true,
+    };
+
+    /// The param names that commonly carry secrets, like OAuth tokens & codes, API keys, & signed URL signatures.
+    pub const SENSITIVE_NAMES: &'static [&'static str] = &[
+        "access_token",
+        "api_key",
+        "apikey",
+        "auth",
+        "client_secret",
+        "code",
+        "id_token",
+        "key",
+        "passwd",
+        "password",
+        "pwd",
+        "refresh_token",
+        "secret",
+        "session",
+        "sig",
+        "signature",
+        "token",
+        "x-amz-credential",
+        "x-amz-security-token",
+        "x-amz-signature",
+    ];
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl RedactionPolicy {
+    //! Builders
+
+    /// Sets the sensitive param `names`. The names are matched case-insensitively.
+    pub const fn with_names(mut self, names: &'static [&'static str]) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Sets the `mask` that replaces the redacted values.
+    pub const fn with_mask(mut self, mask: &'static str) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Sets whether the user info password is redacted.
+    pub const fn with_password_redacted(mut self, redact: bool) -> Self {
+        self.redact_password = redact;
+        self
+    }
+}
+
+impl RedactionPolicy {
+    //! Properties
+
+    /// Gets the mask that replaces the redacted values.
+    #[must_use]
+    pub const fn mask(&self) -> &'static str {
+        self.mask
+    }
+
+    /// Checks if the user info password is redacted.
+    #[must_use]
+    pub const fn redacts_password(&self) -> bool {
+        self.redact_password
+    }
+
+    /// Checks if the param `name` is sensitive.
+    ///
+    /// The name is percent-decoded before it is compared, so `access%5Ftoken` is sensitive in a URL parsed with a
+    /// normalizer that keeps the escapes. A name that cannot be decoded is compared as it is.
+    #[must_use]
+    pub fn is_sensitive(&self, name: &str) -> bool {
+        let name: Cow<str> = percent::decode(name).unwrap_or(Cow::Borrowed(name));
+        self.names
+            .iter()
+            .any(|sensitive| sensitive.eq_ignore_ascii_case(name.as_ref()))
+    }
+}
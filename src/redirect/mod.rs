@@ -0,0 +1,13 @@
+//! Open redirect protection for the user-supplied URLs a server redirects to, like the `next` param of a login flow.
+//!
+//! A [`RedirectPolicy`] resolves an untrusted reference against the URL of the current request & allows the target
+//! only when it is the same origin or an allowed origin. The references that browsers & servers read differently than
+//! RFC 3986, like `/\evil.com` & `https:evil.com`, are rejected before they are resolved.
+//!
+//! The resolved target is a full URL, so it should be the one redirected to rather than the original reference.
+
+pub use redirect_error::*;
+pub use redirect_policy::*;
+
+mod redirect_error;
+mod redirect_policy;
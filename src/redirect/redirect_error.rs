@@ -0,0 +1,75 @@
+use crate::Error;
+use crate::redirect::RedirectError::*;
+use std::fmt::{Display, Formatter};
+
+/// An error checking a redirect with a [`RedirectPolicy`](crate::redirect::RedirectPolicy).
+#[non_exhaustive]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum RedirectError {
+    /// The reference was empty.
+    Empty,
+
+    /// The reference had a control char or a space, which browsers strip or trim. (`/\t/evil.com`)
+    ControlChar,
+
+    /// The reference had a '\' char, which browsers read as a '/' char. (`/\evil.com`)
+    Backslash,
+
+    /// The reference was scheme-relative. (`//evil.com`)
+    SchemeRelative,
+
+    /// The reference had a scheme but no authority, which browsers read as a relative reference or an authority
+    /// depending on the scheme. (`https:evil.com`)
+    MissingAuthority,
+
+    /// The reference had user info, which hides the host behind a trusted name. (`https://trusted.com@evil.com`)
+    UserInfo,
+
+    /// The reference path had an encoded '/' or '\' char, which servers may decode into a scheme-relative reference.
+    /// (`/%2F%2Fevil.com`)
+    EncodedSlash,
+
+    /// The resolved URL was invalid.
+    InvalidReference(Error),
+
+    /// The resolved URL did not have the `http` or `https` scheme.
+    DisallowedScheme,
+
+    /// The resolved URL was not the same origin or an allowed origin.
+    CrossOrigin,
+}
+
+impl RedirectError {
+    //! Display
+
+    /// Gets the error message.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Empty => "empty reference",
+            ControlChar => "control char in reference",
+            Backslash => "backslash in reference",
+            SchemeRelative => "scheme-relative reference",
+            MissingAuthority => "scheme without authority",
+            UserInfo => "user info in reference",
+            EncodedSlash => "encoded slash in path",
+            InvalidReference(error) => error.message(),
+            DisallowedScheme => "disallowed scheme",
+            CrossOrigin => "cross-origin redirect",
+        }
+    }
+}
+
+impl Display for RedirectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.message())
+    }
+}
+
+impl std::error::Error for RedirectError {}
+
+impl From<Error> for RedirectError {
+    fn from(error: Error) -> Self {
+        InvalidReference(error)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::WebUrl;
+use crate::serde::WebUrlVisitor;
+
+impl ::serde::Serialize for WebUrl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WebUrl {
+    /// The URL is validated & normalized like [`WebUrl::from_str`](std::str::FromStr::from_str), except that the user
+    /// info is accepted so a serialized URL with user info can be deserialized.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_string(WebUrlVisitor)
+    }
+}
+
+/// Implements `Serialize` & a borrowed `Deserialize` for a reference type that serializes as its `Display` string.
+macro_rules! impl_serde_str_ref {
+    ($ty:ident, $expecting:literal) => {
+        impl<'a> ::serde::Serialize for crate::$ty<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de: 'a, 'a> ::serde::Deserialize<'de> for crate::$ty<'a> {
+            /// The string is borrowed from the input, so it must be valid as is & must not contain escape sequences.
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_str(crate::serde::TryFromStrVisitor::new($expecting))
+            }
+        }
+    };
+}
+
+impl_serde_str_ref!(WebUrlRef, "a borrowed normalized web-based URL string");
+impl_serde_str_ref!(Scheme, "a borrowed scheme string");
+impl_serde_str_ref!(UserInfo, "a borrowed user info string");
+impl_serde_str_ref!(Path, "a borrowed path string");
+impl_serde_str_ref!(Query, "a borrowed query string");
+impl_serde_str_ref!(Param, "a borrowed query parameter string");
+impl_serde_str_ref!(Fragment, "a borrowed fragment string");
+
+#[cfg(test)]
+mod tests {
+    use crate::{Fragment, Param, Path, Query, Scheme, UserInfo, WebUrl, WebUrlRef};
+    use std::str::FromStr;
+
+    #[test]
+    fn web_url() -> Result<(), Box<dyn std::error::Error>> {
+        let url: WebUrl = WebUrl::from_str("https://example.com/a?b#c")?;
+        let json: String = serde_json::to_string(&url)?;
+        assert_eq!(json, "\"https://example.com/a?b#c\"");
+        assert_eq!(serde_json::from_str::<WebUrl>(json.as_str())?, url);
+        Ok(())
+    }
+
+    #[test]
+    fn web_url_user_info() -> Result<(), Box<dyn std::error::Error>> {
+        let url: WebUrl = WebUrl::parse_with_user_info("https://user:pw@example.com/")?;
+        let json: String = serde_json::to_string(&url)?;
+        assert_eq!(json, "\"https://user:pw@example.com/\"");
+        assert_eq!(serde_json::from_str::<WebUrl>(json.as_str())?, url);
+        assert_eq!(serde_json::from_value::<WebUrl>(serde_json::to_value(&url)?)?, url);
+        Ok(())
+    }
+
+    #[test]
+    fn web_url_normalized() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, &str)] = &[
+            ("\"HTTPS://Example.COM:443/a/../b\"", "https://example.com/b"),
+            ("\"http://example.com\"", "http://example.com/"),
+            ("\"http://ex\\u0061mple.com/%7e\"", "http://example.com/~"),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = serde_json::from_str(input)?;
+            assert_eq!(url.as_str(), *expected, "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn web_url_invalid() {
+        let test_cases: &[(&str, &str)] = &[
+            ("\"http:///p\"", "invalid host: empty host at byte 7"),
+            ("\"http://ho st/\"", "invalid host: invalid char ' ' at bytes 9..10"),
+            (
+                "\"http://ho\\u0020st/\"",
+                "invalid host: invalid char ' ' at bytes 9..10",
+            ),
+            ("42", "invalid type: integer `42`, expected a web-based URL string"),
+        ];
+        for (input, expected) in test_cases {
+            let error: String = serde_json::from_str::<WebUrl>(input).unwrap_err().to_string();
+            assert!(error.starts_with(expected), "input={} error={}", input, error);
+        }
+    }
+
+    #[test]
+    fn borrowed_parts() -> Result<(), Box<dyn std::error::Error>> {
+        let json: &str = r##"["https","user","/a/b","?c=d","c=d","#e","https://example.com/"]"##;
+        let parts: (Scheme, UserInfo, Path, Query, Param, Fragment, WebUrlRef) = serde_json::from_str(json)?;
+        assert_eq!(parts.0, Scheme::HTTPS);
+        assert_eq!(parts.1.as_str(), "user");
+        assert_eq!(parts.2.as_str(), "/a/b");
+        assert_eq!(parts.3.as_str(), "?c=d");
+        assert_eq!(parts.4.to_string(), "c=d");
+        assert_eq!(parts.5.as_str(), "#e");
+        assert_eq!(parts.6.as_str(), "https://example.com/");
+        assert_eq!(serde_json::to_string(&parts)?, json);
+        Ok(())
+    }
+
+    /// The reference types borrow from the input, so they cannot be deserialized from an escaped or invalid string.
+    #[test]
+    fn borrowed_parts_invalid() {
+        assert!(serde_json::from_str::<Path>("\"/a\\u0062\"").is_err());
+        assert!(serde_json::from_str::<Path>("\"a/b\"").is_err());
+        assert!(serde_json::from_str::<Query>("\"c=d\"").is_err());
+        assert!(serde_json::from_str::<Fragment>("\"e\"").is_err());
+        assert!(serde_json::from_str::<WebUrlRef>("\"HTTPS://example.com/\"").is_err());
+
+        let error: String = serde_json::from_str::<Scheme>("42").unwrap_err().to_string();
+        assert!(error.contains("a borrowed scheme string"), "error={}", error);
+    }
+}
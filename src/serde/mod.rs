@@ -0,0 +1,12 @@
+//! Serde support for the URL types.
+//!
+//! This module is private, so its docs are not published. The wire contract these impls uphold is stated in the
+//! README, under `Serde`.
+
+pub(crate) use try_from_str_visitor::*;
+pub(crate) use web_url_visitor::*;
+
+mod try_from_str_visitor;
+mod web_url_visitor;
+
+mod impl_serde;
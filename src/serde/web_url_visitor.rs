@@ -0,0 +1,62 @@
+use crate::parse::diagnose;
+use crate::{Normalizer, WebUrl};
+use serde::de::{Error, Unexpected, Visitor};
+use std::fmt::Formatter;
+
+/// A serde visitor that parses & normalizes a web-based URL, reusing the buffer of an owned string.
+///
+/// The errors have the message of the detailed parse error, like `invalid host: empty host at byte 7`.
+pub(crate) struct WebUrlVisitor;
+
+impl WebUrlVisitor {
+    //! Constants
+
+    /// The normalizer of the URLs. The user info is accepted so a serialized URL with user info can be deserialized.
+    const NORMALIZER: Normalizer = Normalizer::DEFAULT.with_user_info(true);
+}
+
+impl<'de> Visitor<'de> for WebUrlVisitor {
+    type Value = WebUrl;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a web-based URL string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Self::NORMALIZER
+            .parse(v)
+            .map_err(|error| E::custom(diagnose(v, error, Self::NORMALIZER)))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Self::NORMALIZER
+            .parse_string(v)
+            .map_err(|error| E::custom(error.parse_error()))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match std::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match String::from_utf8(v) {
+            Ok(s) => self.visit_string(s),
+            Err(error) => Err(E::invalid_value(Unexpected::Bytes(error.as_bytes()), &self)),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use address::{IPAddress, IPv4Address, IPv6Address};
+
+/// The special-use class of an IP address.
+///
+/// # IANA
+/// <https://www.iana.org/assignments/iana-ipv4-special-registry/>
+/// <https://www.iana.org/assignments/iana-ipv6-special-registry/>
+#[must_use]
+#[non_exhaustive]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum AddressClass {
+    /// The unspecified address & the "this network" block. (`0.0.0.0/8`, `::`)
+    Unspecified,
+
+    /// A loopback address. (`127.0.0.0/8`, `::1`)
+    Loopback,
+
+    /// A private network address. (`10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16`)
+    Private,
+
+    /// A shared address space address used by carrier-grade NAT. (`100.64.0.0/10`)
+    SharedAddressSpace,
+
+    /// A link-local address. (`169.254.0.0/16`, `fe80::/10`)
+    LinkLocal,
+
+    /// A cloud metadata service address. (`169.254.169.254`, `100.100.100.200`, `fd00:ec2::254`)
+    Metadata,
+
+    /// A multicast address. (`224.0.0.0/4`, `ff00::/8`)
+    Multicast,
+
+    /// The limited broadcast address. (`255.255.255.255`)
+    Broadcast,
+
+    /// A unique local address. (`fc00::/7`)
+    UniqueLocal,
+
+    /// An IPv4-mapped IPv6 address. (`::ffff:0:0/96`)
+    IPv4Mapped,
+
+    /// An IPv6 address that a translator or tunnel connects to an embedded IPv4 address: the NAT64 blocks
+    /// (`64:ff9b::/96`, `64:ff9b:1::/48`), 6to4 (`2002::/16`), & Teredo (`2001::/32`).
+    IPv4Embedded,
+
+    /// A reserved address: the documentation, benchmarking, & protocol assignment blocks, the future use block
+    /// (`240.0.0.0/4`), the deprecated IPv4-compatible & site-local IPv6 blocks, & the IPv6 discard block.
+    Reserved,
+
+    /// A public address.
+    Public,
+}
+
+impl AddressClass {
+    //! Classification
+
+    /// Gets the class of the `ip`.
+    pub const fn of(ip: IPAddress) -> Self {
+        match ip {
+            IPAddress::V4(ip) => Self::of_v4(ip),
+            IPAddress::V6(ip) => Self::of_v6(ip),
+        }
+    }
+
+    /// Gets the class of the IPv4 `ip`.
+    pub const fn of_v4(ip: IPv4Address) -> Self {
+        match ip.address() {
+            [0, ..] => Self::Unspecified,
+            [127, ..] => Self::Loopback,
+            [10, ..] | [172, 16..=31, ..] | [192, 168, ..] => Self::Private,
+            [100, 100, 100, 200] | [169, 254, 169, 254] => Self::Metadata,
+            [100, 64..=127, ..] => Self::SharedAddressSpace,
+            [169, 254, ..] => Self::LinkLocal,
+            [255, 255, 255, 255] => Self::Broadcast,
+            [224..=239, ..] => Self::Multicast,
+            [192, 0, 0 | 2, _] | [198, 18 | 19, ..] | [198, 51, 100, _] | [203, 0, 113, _] | [240..=255, ..] => {
+                Self::Reserved
+            }
+            _ => Self::Public,
+        }
+    }
+
+    /// Gets the class of the IPv6 `ip`.
+    pub const fn of_v6(ip: IPv6Address) -> Self {
+        match ip.segments() {
+            [0, 0, 0, 0, 0, 0, 0, 0] => Self::Unspecified,
+            [0, 0, 0, 0, 0, 0, 0, 1] => Self::Loopback,
+            [0, 0, 0, 0, 0, 0xFFFF, _, _] => Self::IPv4Mapped,
+            [0x64, 0xFF9B, 0, 0, 0, 0, _, _] | [0x64, 0xFF9B, 1, ..] | [0x2002, ..] | [0x2001, 0, ..] => {
+                Self::IPv4Embedded
+            }
+            [0, 0, 0, 0, 0, 0, _, _] | [0x100, 0, 0, 0, _, _, _, _] | [0x2001, 0xDB8, _, _, _, _, _, _] => {
+                Self::Reserved
+            }
+            [0xFD00, 0xEC2, 0, 0, 0, 0, 0, 0x254] => Self::Metadata,
+            [0xFE80..=0xFEBF, ..] => Self::LinkLocal,
+            [0xFEC0..=0xFEFF, ..] => Self::Reserved,
+            [0xFC00..=0xFDFF, ..] => Self::UniqueLocal,
+            [0xFF00..=0xFFFF, ..] => Self::Multicast,
+            _ => Self::Public,
+        }
+    }
+}
+
+impl AddressClass {
+    //! Embedded Addresses
+
+    /// Gets the IPv4 addresses embedded in the IPv6 `ip` of the `IPv4Mapped` or `IPv4Embedded` class, which are the
+    /// addresses it connects to.
+    ///
+    /// The NAT64 addresses embed the IPv4 address in the last 32 bits, & the `64:ff9b:1::/48` addresses may embed it
+    /// after the 48-bit prefix as well. A 6to4 address embeds it after its 16-bit prefix, & a Teredo address embeds the
+    /// server address after its 32-bit prefix & the obfuscated client address in the last 32 bits.
+    pub(crate) const fn embedded_v4(ip: IPv6Address) -> [Option<IPv4Address>; 2] {
+        let a: [u8; 16] = ip.address();
+        let last: IPv4Address = IPv4Address::new([a[12], a[13], a[14], a[15]]);
+        match ip.segments() {
+            [0, 0, 0, 0, 0, 0xFFFF, _, _] | [0x64, 0xFF9B, 0, 0, 0, 0, _, _] => [Some(last), None],
+            [0x64, 0xFF9B, 1, ..] => [Some(last), Some(IPv4Address::new([a[6], a[7], a[9], a[10]]))],
+            [0x2002, ..] => [Some(IPv4Address::new([a[2], a[3], a[4], a[5]])), None],
+            [0x2001, 0, ..] => [
+                Some(IPv4Address::new([a[4], a[5], a[6], a[7]])),
+                Some(IPv4Address::new([!a[12], !a[13], !a[14], !a[15]])),
+            ],
+            _ => [None, None],
+        }
+    }
+}
+
+impl AddressClass {
+    //! Properties
+
+    /// Gets the bit of the class in a class set.
+    pub(crate) const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssrf::AddressClass;
+    use crate::ssrf::AddressClass::*;
+    use address::IPAddress;
+    use std::str::FromStr;
+
+    #[test]
+    fn of() {
+        let test_cases: &[(&str, AddressClass)] = &[
+            ("0.0.0.0", Unspecified),
+            ("0.1.2.3", Unspecified),
+            ("127.0.0.1", Loopback),
+            ("127.255.255.254", Loopback),
+            ("10.1.2.3", Private),
+            ("172.16.0.1", Private),
+            ("172.31.255.255", Private),
+            ("172.32.0.1", Public),
+            ("192.168.1.1", Private),
+            ("100.64.0.1", SharedAddressSpace),
+            ("100.127.255.255", SharedAddressSpace),
+            ("100.128.0.1", Public),
+            ("169.254.1.1", LinkLocal),
+            ("169.254.169.254", Metadata),
+            ("100.100.100.200", Metadata),
+            ("224.0.0.1", Multicast),
+            ("239.255.255.250", Multicast),
+            ("255.255.255.255", Broadcast),
+            ("240.0.0.1", Reserved),
+            ("192.0.2.1", Reserved),
+            ("198.18.0.1", Reserved),
+            ("8.8.8.8", Public),
+            ("::", Unspecified),
+            ("::1", Loopback),
+            ("::ffff:127.0.0.1", IPv4Mapped),
+            ("::ffff:8.8.8.8", IPv4Mapped),
+            ("64:ff9b::a9fe:a9fe", IPv4Embedded),
+            ("64:ff9b:1::7f00:1", IPv4Embedded),
+            ("2002:7f00:1::", IPv4Embedded),
+            ("2001:0:4136:e378:8000:63bf:3fff:fdd2", IPv4Embedded),
+            ("2001:1::1", Public),
+            ("::127.0.0.1", Reserved),
+            ("2001:db8::1", Reserved),
+            ("100::1", Reserved),
+            ("fe80::1", LinkLocal),
+            ("fec0::1", Reserved),
+            ("fc00::1", UniqueLocal),
+            ("fd12:3456::1", UniqueLocal),
+            ("fd00:ec2::254", Metadata),
+            ("ff02::1", Multicast),
+            ("2606:4700::1111", Public),
+        ];
+        for (input, expected) in test_cases {
+            let ip: IPAddress = IPAddress::from_str(input).unwrap();
+            assert_eq!(AddressClass::of(ip), *expected, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use crate::WebUrl;
+use crate::parse::{ends_in_number, parse_whatwg_ipv4};
+use crate::ssrf::AddressClass::*;
+use crate::ssrf::{AddressClass, Verdict};
+use address::{DomainRef, HostRef, IPAddress, IPv4Address};
+
+/// A policy for the destinations a server may fetch on behalf of a user.
+///
+/// The [`Self::DEFAULT`] policy allows only the public addresses & the ports 80 & 443, & denies the special-use domain
+/// names. It is configured with the `with_` methods, like the [`Normalizer`](crate::Normalizer).
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use web_url::WebUrl;
+/// use web_url::ssrf::{AddressClass, DestinationPolicy, Verdict};
+///
+/// let policy: DestinationPolicy = DestinationPolicy::DEFAULT.with_ports(&[443, 8443]);
+/// let url: WebUrl = WebUrl::from_str("https://169.254.169.254/latest/meta-data/").unwrap();
+/// assert!(matches!(policy.check(&url), Verdict::DeniedAddress(_, AddressClass::Metadata)));
+/// ```
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct DestinationPolicy {
+    denied_classes: u16,
+    ports: Option<&'static [u16]>,
+    deny_special_use_domains: bool,
+}
+
+impl DestinationPolicy {
+    //! Constants
+
+    /// The default policy. (see [`DestinationPolicy`])
+    pub const DEFAULT: Self = Self {
+        denied_classes: !Public.bit(),
+        ports: Some(&[80, 443]),
+        deny_special_use_domains: true,
+    };
+
+    /// The special-use domain names that resolve to internal addresses. Their subdomains are special-use as well.
+    ///
+    /// - `localhost`: [RFC 6761](https://www.rfc-editor.org/rfc/rfc6761#section-6.3)
+    /// - `local`: [RFC 6762](https://www.rfc-editor.org/rfc/rfc6762)
+    /// - `internal`: reserved by ICANN for private use
+    /// - `home.arpa`: [RFC 8375](https://www.rfc-editor.org/rfc/rfc8375)
+    pub const SPECIAL_USE_DOMAINS: &'static [&'static str] = &["localhost", "local", "internal", "home.arpa"];
+}
+
+impl Default for DestinationPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl DestinationPolicy {
+    //! Builders
+
+    /// Allows the addresses of the `class`.
+    ///
+    /// Allowing `IPv4Mapped` or `IPv4Embedded` checks the embedded IPv4 addresses instead, so `::ffff:127.0.0.1` &
+    /// `64:ff9b::7f00:1` are still denied as a `Loopback` address.
+    pub const fn with_allowed(mut self, class: AddressClass) -> Self {
+        self.denied_classes &= !class.bit();
+        self
+    }
+
+    /// Denies the addresses of the `class`.
+    pub const fn with_denied(mut self, class: AddressClass) -> Self {
+        self.denied_classes |= class.bit();
+        self
+    }
+
+    /// Allows only the `ports`. The effective port is checked, so a URL with no port has its scheme's default port.
+    pub const fn with_ports(mut self, ports: &'static [u16]) -> Self {
+        self.ports = Some(ports);
+        self
+    }
+
+    /// Allows every port.
+    pub const fn with_any_port(mut self) -> Self {
+        self.ports = None;
+        self
+    }
+
+    /// Sets whether the special-use domain names are denied. (see [`Self::SPECIAL_USE_DOMAINS`])
+    pub const fn with_special_use_domains_denied(mut self, deny: bool) -> Self {
+        self.deny_special_use_domains = deny;
+        self
+    }
+}
+
+impl DestinationPolicy {
+    //! Checks
+
+    /// Checks the port & host of the `url`.
+    ///
+    /// A domain host is checked by its name alone, so a public domain that resolves to an internal address is allowed.
+    /// Use [`Self::check_resolved`] to check the resolved addresses as well.
+    pub fn check(&self, url: &WebUrl) -> Verdict {
+        if let Some(ports) = self.ports {
+            match url.effective_port() {
+                Some(port) if ports.contains(&port) => {}
+                port => return Verdict::DeniedPort(port),
+            }
+        }
+        match url.host() {
+            HostRef::Address(ip) => self.check_address(ip),
+            HostRef::Name(domain) => self.check_domain(domain),
+        }
+    }
+
+    /// Checks the port & host of the `url` & the addresses its domain host resolves to with the `resolve` function.
+    ///
+    /// The resolver is only called for a domain host that passes [`Self::check`]. Every resolved address must be
+    /// allowed, & a domain that resolves to no addresses is `Unresolved`. The caller must connect to the checked
+    /// addresses rather than resolve the domain again.
+    pub fn check_resolved<F, I>(&self, url: &WebUrl, resolve: F) -> Verdict
+    where
+        F: FnOnce(DomainRef<'_>) -> I,
+        I: IntoIterator<Item = IPAddress>,
+    {
+        let verdict: Verdict = self.check(url);
+        let HostRef::Name(domain) = url.host() else {
+            return verdict;
+        };
+        if !verdict.is_allowed() {
+            return verdict;
+        }
+
+        let mut resolved: bool = false;
+        for ip in resolve(domain) {
+            resolved = true;
+            let verdict: Verdict = self.check_address(ip);
+            if !verdict.is_allowed() {
+                return verdict;
+            }
+        }
+        if resolved {
+            Verdict::Allowed
+        } else {
+            Verdict::Unresolved
+        }
+    }
+
+    /// Checks the `ip` address.
+    pub fn check_address(&self, ip: IPAddress) -> Verdict {
+        let class: AddressClass = AddressClass::of(ip);
+        if self.denied_classes & class.bit() != 0 {
+            return Verdict::DeniedAddress(ip, class);
+        }
+        if let IPAddress::V6(v6) = ip
+            && matches!(class, IPv4Mapped | IPv4Embedded)
+        {
+            // The embedded addresses are checked as the IPv4 addresses they connect to.
+            for v4 in AddressClass::embedded_v4(v6).into_iter().flatten() {
+                if let Verdict::DeniedAddress(_, class) = self.check_address(v4.to_ip()) {
+                    return Verdict::DeniedAddress(ip, class);
+                }
+            }
+        }
+        Verdict::Allowed
+    }
+
+    /// Checks the `domain` name.
+    ///
+    /// A domain that ends in a number is checked as the IPv4 address the WHATWG URL Standard reads it as, since
+    /// resolvers read `2130706433` & `0x7f.1` as `127.0.0.1` too.
+    fn check_domain(&self, domain: DomainRef) -> Verdict {
+        let name: &str = domain.name();
+        if ends_in_number(name) {
+            return match parse_whatwg_ipv4(name) {
+                Some(ip) => self.check_address(IPv4Address::from(ip).to_ip()),
+                None => Verdict::NumericDomain,
+            };
+        }
+        if self.deny_special_use_domains && Self::is_special_use_domain(name) {
+            return Verdict::SpecialUseDomain;
+        }
+        Verdict::Allowed
+    }
+
+    /// Checks if the domain `name` is a special-use domain name or a subdomain of one.
+    fn is_special_use_domain(name: &str) -> bool {
+        Self::SPECIAL_USE_DOMAINS.iter().any(|special| {
+            name.strip_suffix(special)
+                .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('.'))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WebUrl;
+    use crate::ssrf::AddressClass::*;
+    use crate::ssrf::{DestinationPolicy, Verdict};
+    use address::IPAddress;
+    use std::str::FromStr;
+
+    fn ip(s: &str) -> IPAddress {
+        IPAddress::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn check() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, Verdict)] = &[
+            ("https://example.com/hook", Verdict::Allowed),
+            ("http://8.8.8.8/", Verdict::Allowed),
+            ("https://[2606:4700::1111]/", Verdict::Allowed),
+            ("http://127.0.0.1/", Verdict::DeniedAddress(ip("127.0.0.1"), Loopback)),
+            ("http://[::1]/", Verdict::DeniedAddress(ip("::1"), Loopback)),
+            ("http://10.0.0.5/", Verdict::DeniedAddress(ip("10.0.0.5"), Private)),
+            (
+                "http://192.168.0.1/",
+                Verdict::DeniedAddress(ip("192.168.0.1"), Private),
+            ),
+            (
+                "http://100.64.1.1/",
+                Verdict::DeniedAddress(ip("100.64.1.1"), SharedAddressSpace),
+            ),
+            (
+                "http://169.254.169.254/",
+                Verdict::DeniedAddress(ip("169.254.169.254"), Metadata),
+            ),
+            ("http://[fe80::1]/", Verdict::DeniedAddress(ip("fe80::1"), LinkLocal)),
+            ("http://[fd00::1]/", Verdict::DeniedAddress(ip("fd00::1"), UniqueLocal)),
+            ("http://224.0.0.1/", Verdict::DeniedAddress(ip("224.0.0.1"), Multicast)),
+            ("http://0.0.0.0/", Verdict::DeniedAddress(ip("0.0.0.0"), Unspecified)),
+            (
+                "http://[::ffff:8.8.8.8]/",
+                Verdict::DeniedAddress(ip("::ffff:8.8.8.8"), IPv4Mapped),
+            ),
+            (
+                "http://[64:ff9b::a9fe:a9fe]/",
+                Verdict::DeniedAddress(ip("64:ff9b::a9fe:a9fe"), IPv4Embedded),
+            ),
+            (
+                "http://[64:ff9b::7f00:1]/",
+                Verdict::DeniedAddress(ip("64:ff9b::7f00:1"), IPv4Embedded),
+            ),
+            (
+                "http://[2002:7f00:1::]/",
+                Verdict::DeniedAddress(ip("2002:7f00:1::"), IPv4Embedded),
+            ),
+            (
+                "http://[2001:0:4136:e378:8000:63bf:3fff:fdd2]/",
+                Verdict::DeniedAddress(ip("2001:0:4136:e378:8000:63bf:3fff:fdd2"), IPv4Embedded),
+            ),
+            ("http://2130706433/", Verdict::DeniedAddress(ip("127.0.0.1"), Loopback)),
+            ("http://0x7f.1/", Verdict::DeniedAddress(ip("127.0.0.1"), Loopback)),
+            ("http://1.2.3.999/", Verdict::NumericDomain),
+            ("http://localhost/", Verdict::SpecialUseDomain),
+            ("http://api.localhost/", Verdict::SpecialUseDomain),
+            ("http://printer.local/", Verdict::SpecialUseDomain),
+            ("http://metadata.google.internal/", Verdict::SpecialUseDomain),
+            ("http://router.home.arpa/", Verdict::SpecialUseDomain),
+            ("http://notlocalhost/", Verdict::Allowed),
+            ("http://example.com:8080/", Verdict::DeniedPort(Some(8080))),
+            ("https://example.com:22/", Verdict::DeniedPort(Some(22))),
+            ("custom://example.com/", Verdict::DeniedPort(None)),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(DestinationPolicy::DEFAULT.check(&url), *expected, "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn builders() -> Result<(), Box<dyn std::error::Error>> {
+        let policy: DestinationPolicy = DestinationPolicy::DEFAULT
+            .with_allowed(Private)
+            .with_allowed(IPv4Mapped)
+            .with_allowed(IPv4Embedded)
+            .with_any_port()
+            .with_special_use_domains_denied(false);
+        let test_cases: &[(&str, Verdict)] = &[
+            ("http://10.0.0.5:8080/", Verdict::Allowed),
+            ("http://localhost/", Verdict::Allowed),
+            ("custom://example.com/", Verdict::Allowed),
+            ("http://[::ffff:8.8.8.8]/", Verdict::Allowed),
+            ("http://[::ffff:10.0.0.1]/", Verdict::Allowed),
+            (
+                "http://[::ffff:127.0.0.1]/",
+                Verdict::DeniedAddress(ip("::ffff:127.0.0.1"), Loopback),
+            ),
+            ("http://127.0.0.1/", Verdict::DeniedAddress(ip("127.0.0.1"), Loopback)),
+            ("http://[64:ff9b::808:808]/", Verdict::Allowed),
+            (
+                "http://[64:ff9b::a9fe:a9fe]/",
+                Verdict::DeniedAddress(ip("64:ff9b::a9fe:a9fe"), Metadata),
+            ),
+            (
+                "http://[2002:7f00:1::]/",
+                Verdict::DeniedAddress(ip("2002:7f00:1::"), Loopback),
+            ),
+            ("http://[2002:808:808::]/", Verdict::Allowed),
+            (
+                "http://[2001:0:808:808::80ff:fffe]/",
+                Verdict::DeniedAddress(ip("2001:0:808:808::80ff:fffe"), Loopback),
+            ),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(policy.check(&url), *expected, "input={}", input);
+        }
+
+        let policy: DestinationPolicy = DestinationPolicy::DEFAULT.with_denied(Public);
+        assert_eq!(
+            policy.check_address(ip("8.8.8.8")),
+            Verdict::DeniedAddress(ip("8.8.8.8"), Public)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_resolved() -> Result<(), Box<dyn std::error::Error>> {
+        let resolve = |domain: address::DomainRef| -> Vec<IPAddress> {
+            match domain.name() {
+                "public.example" => vec![ip("93.184.215.14"), ip("2606:2800:21f:cb07:6820:80da:af6b:8b2c")],
+                "rebind.example" => vec![ip("93.184.215.14"), ip("127.0.0.1")],
+                "metadata.example" => vec![ip("169.254.169.254")],
+                _ => vec![],
+            }
+        };
+        let test_cases: &[(&str, Verdict)] = &[
+            ("https://public.example/", Verdict::Allowed),
+            (
+                "https://rebind.example/",
+                Verdict::DeniedAddress(ip("127.0.0.1"), Loopback),
+            ),
+            (
+                "https://metadata.example/",
+                Verdict::DeniedAddress(ip("169.254.169.254"), Metadata),
+            ),
+            ("https://missing.example/", Verdict::Unresolved),
+            ("https://localhost/", Verdict::SpecialUseDomain),
+            ("https://8.8.8.8/", Verdict::Allowed),
+            ("https://10.0.0.1/", Verdict::DeniedAddress(ip("10.0.0.1"), Private)),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(
+                DestinationPolicy::DEFAULT.check_resolved(&url, resolve),
+                *expected,
+                "input={}",
+                input
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn verdict_display() {
+        assert_eq!(Verdict::Allowed.to_string(), "allowed");
+        assert_eq!(
+            Verdict::DeniedAddress(ip("127.0.0.1"), Loopback).to_string(),
+            "denied address: 127.0.0.1 is Loopback"
+        );
+        assert_eq!(Verdict::DeniedPort(Some(22)).to_string(), "denied port: 22");
+    }
+}
@@ -0,0 +1,16 @@
+//! Server-side request forgery (SSRF) protection for the URLs a server fetches on behalf of a user.
+//!
+//! A [`DestinationPolicy`] checks the host & port of a URL, & optionally the addresses its domain resolves to, against
+//! the internal destinations a server can reach but an outside user must not, like loopback, private network, & cloud
+//! metadata addresses.
+//!
+//! The resolved addresses must be the ones connected to. Resolving the domain again after the check lets a DNS
+//! rebinding attack swap in an internal address.
+
+pub use address_class::*;
+pub use destination_policy::*;
+pub use verdict::*;
+
+mod address_class;
+mod destination_policy;
+mod verdict;
@@ -0,0 +1,53 @@
+use crate::ssrf::AddressClass;
+use address::IPAddress;
+use std::fmt::{Display, Formatter};
+
+/// The verdict of a [`DestinationPolicy`](crate::ssrf::DestinationPolicy) check.
+#[must_use]
+#[non_exhaustive]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Verdict {
+    /// The destination is allowed.
+    Allowed,
+
+    /// The IP address host, or an address the domain host resolved to, is in a denied class.
+    DeniedAddress(IPAddress, AddressClass),
+
+    /// The host is a special-use domain name that resolves to an internal address, like `localhost` or
+    /// `metadata.google.internal`.
+    SpecialUseDomain,
+
+    /// The host is a domain name that ends in a number but is not an IPv4 address, which resolvers may read as an
+    /// address anyway. (`1.2.3.999`)
+    NumericDomain,
+
+    /// The port is denied. (`None` when the URL has no port & its scheme has no default port)
+    DeniedPort(Option<u16>),
+
+    /// The domain host resolved to no addresses.
+    Unresolved,
+}
+
+impl Verdict {
+    //! Properties
+
+    /// Checks if the destination is allowed.
+    #[must_use]
+    pub const fn is_allowed(self) -> bool {
+        matches!(self, Self::Allowed)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allowed => f.write_str("allowed"),
+            Self::DeniedAddress(ip, class) => write!(f, "denied address: {} is {:?}", ip, class),
+            Self::SpecialUseDomain => f.write_str("denied special-use domain"),
+            Self::NumericDomain => f.write_str("denied numeric domain"),
+            Self::DeniedPort(Some(port)) => write!(f, "denied port: {}", port),
+            Self::DeniedPort(None) => f.write_str("denied port: no port"),
+            Self::Unresolved => f.write_str("unresolved domain"),
+        }
+    }
+}
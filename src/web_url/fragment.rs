@@ -1,3 +1,4 @@
+use crate::parse::{percent_normalized_len, write_percent_normalized};
 use crate::{Fragment, WebUrl};
 
 impl WebUrl {
@@ -28,6 +29,8 @@ impl WebUrl {
 
     /// Sets the `fragment`.
     ///
+    /// The percent-encoding of the `fragment` is normalized, so `#%7e` is set as `#~`.
+    ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn set_fragment<'a, F>(&mut self, fragment: F)
@@ -42,11 +45,11 @@ impl WebUrl {
         // The length is checked before anything is modified so an over-long URL panics with the URL intact. No offset
         // changes here since the fragment is last, but the URL must still stay short enough for its own parser to
         // accept it.
-        Self::check_len(base_len + fragment.map(|f| f.as_str().len()).unwrap_or(0));
+        Self::check_len(base_len + fragment.map(|f| percent_normalized_len(f.as_str())).unwrap_or(0));
 
         self.url.truncate(base_len);
         if let Some(fragment) = fragment {
-            self.url.push_str(fragment.as_str())
+            write_percent_normalized(fragment.as_str(), &mut self.url);
         }
 
         debug_assert!(self.is_consistent());
@@ -91,6 +94,10 @@ mod tests {
         url.set_fragment(Fragment::try_from("#fragment")?);
         assert_eq!(url.as_str(), "https://example.com/#fragment");
 
+        // The percent-encoding of the fragment is normalized.
+        url.set_fragment(Fragment::try_from("#%7e%2f")?);
+        assert_eq!(url.as_str(), "https://example.com/#~%2F");
+
         Ok(())
     }
 
@@ -1,6 +1,8 @@
 use crate::parse;
 use crate::{Error, WebUrl};
 use address::{DomainRef, HostRef, IPAddress};
+#[cfg(feature = "idna")]
+use std::borrow::Cow;
 
 impl WebUrl {
     //! Host
@@ -14,6 +16,25 @@ impl WebUrl {
         }
     }
 
+    /// Gets the host string with its A-labels converted to their Unicode U-label form for display.
+    ///
+    /// The host is borrowed when nothing needs converting: an IP address host, a domain with no `xn--` labels, or a
+    /// domain with an `xn--` label that is not valid Punycode, which is left in its ASCII form. Only a URL made with
+    /// `new_unchecked` can have one since the A-labels are validated when parsing.
+    /// (`xn--bcher-kva.example` -> `bücher.example`)
+    #[cfg(feature = "idna")]
+    #[must_use]
+    pub fn host_unicode(&self) -> Cow<'_, str> {
+        let host: &str = self.host_str();
+        if self.ip.is_some() || !host.split('.').any(|label| label.starts_with("xn--")) {
+            return Cow::Borrowed(host);
+        }
+        match unsafe { DomainRef::new_unchecked(host) }.to_unicode() {
+            Ok(host) => Cow::Owned(host),
+            Err(_) => Cow::Borrowed(host),
+        }
+    }
+
     /// Gets the host string.
     ///
     /// This will be valid:
@@ -21,7 +42,7 @@ impl WebUrl {
     /// - If the host is an IP address it will be in its canonical form.
     /// - If the host is an IPv6 address it will include the '[]' brackets.
     fn host_str(&self) -> &str {
-        let start: usize = (self.scheme_len + 3) as usize;
+        let start: usize = self.host_start as usize;
         let end: usize = self.host_end as usize;
         &self.url[start..end]
     }
@@ -33,7 +54,8 @@ impl WebUrl {
     /// Sets the `host`.
     ///
     /// # Panics
-    /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
+    /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`, or if the host is a domain with an `xn--` label that
+    /// is not a valid A-label. (`xn--a`) The URL is left unmodified.
     pub fn set_host<'a, H>(&mut self, host: H)
     where
         H: Into<HostRef<'a>>,
@@ -51,7 +73,14 @@ impl WebUrl {
             }
         };
 
-        let start: usize = (self.scheme_len + 3) as usize;
+        // A `HostRef` does not decode its `xn--` labels, but a parsed URL never has an invalid one.
+        assert!(
+            parse::check_a_labels(insert).is_ok(),
+            "the host '{}' has an invalid A-label",
+            insert
+        );
+
+        let start: usize = self.host_start as usize;
         let end: usize = self.host_end as usize;
 
         // The length is checked before anything is modified so an over-long URL panics with the URL intact rather than
@@ -83,7 +112,8 @@ impl WebUrl {
     /// Sets the `host`.
     ///
     /// # Panics
-    /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`.
+    /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`, or if the host is a domain with an `xn--` label that
+    /// is not a valid A-label.
     pub fn with_host<'a, H>(mut self, host: H) -> Self
     where
         H: Into<HostRef<'a>>,
@@ -131,6 +161,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "idna")]
+    fn host_domain_unicode() -> Result<(), Box<dyn Error>> {
+        let test_cases: &[(&str, &str)] = &[
+            ("https://B\u{fc}cher.example/", "https://xn--bcher-kva.example/"),
+            (
+                "https://b\u{fc}cher.example:8080",
+                "https://xn--bcher-kva.example:8080/",
+            ),
+            (
+                "https://\u{4f8b}\u{3048}.\u{30c6}\u{30b9}\u{30c8}/p",
+                "https://xn--r8jz45g.xn--zckzah/p",
+            ),
+            // The UTS #46 mapping folds the full width forms, which can turn the host into an IPv4 address.
+            ("https://\u{ff45}\u{ff58}.com/", "https://ex.com/"),
+            ("https://\u{ff11}\u{ff12}\u{ff17}.0.0.1/", "https://127.0.0.1/"),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(url.as_str(), *expected, "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "idna")]
+    fn host_unicode() -> Result<(), Box<dyn Error>> {
+        let test_cases: &[(&str, &str)] = &[
+            ("https://xn--bcher-kva.example/", "b\u{fc}cher.example"),
+            ("https://b\u{fc}cher.example/", "b\u{fc}cher.example"),
+            (
+                "https://xn--r8jz45g.xn--zckzah/",
+                "\u{4f8b}\u{3048}.\u{30c6}\u{30b9}\u{30c8}",
+            ),
+            ("https://example.com/", "example.com"),
+            ("https://[::1]/", "[::1]"),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(url.host_unicode(), *expected, "input={}", input);
+        }
+
+        // An `xn--` label that is not valid Punycode is rejected when parsing.
+        assert_eq!(
+            WebUrl::from_str("https://xn--a.example/").err(),
+            Some(crate::Error::InvalidHost)
+        );
+        Ok(())
+    }
+
     #[test]
     fn host_ipv4() -> Result<(), Box<dyn Error>> {
         let url = WebUrl::from_str("https://127.0.0.1")?;
@@ -194,6 +274,14 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "idna")]
+    #[should_panic(expected = "invalid A-label")]
+    fn set_host_invalid_a_label() {
+        let mut url: WebUrl = WebUrl::from_str("http://host/p").unwrap();
+        url.set_host(DomainRef::try_from("xn--a.example").unwrap());
+    }
+
     #[test]
     fn with_host() -> Result<(), Box<dyn Error>> {
         let url: WebUrl = WebUrl::from_str("http://host/p")?.with_host(DomainRef::EXAMPLE);
@@ -0,0 +1,269 @@
+use crate::Error::{InvalidHost, InvalidPath, InvalidScheme, UrlTooLong};
+use crate::{Error, Scheme, WebUrl};
+use ::http::header::HOST;
+use ::http::uri::{Authority, PathAndQuery};
+use ::http::{Request, Uri};
+
+impl WebUrl {
+    //! HTTP Conversions
+
+    /// Gets the URL string without the fragment, which is the part of the URL an `http::Uri` holds.
+    fn http_uri_str(&self) -> &str {
+        &self.url[..self.query_end as usize]
+    }
+
+    /// Gets the path & query string.
+    fn path_and_query_str(&self) -> &str {
+        &self.url[self.port_end as usize..self.query_end as usize]
+    }
+
+    /// Checks if the URL without its fragment is within the limits of an `http::Uri`.
+    ///
+    /// # Errors
+    /// Returns `InvalidScheme` if the scheme is longer than 64 bytes & `UrlTooLong` if the URL without its fragment is
+    /// longer than 65,534 bytes.
+    fn check_http_uri_limits(&self) -> Result<(), Error> {
+        if self.scheme_len as usize > MAX_HTTP_SCHEME_LEN {
+            Err(InvalidScheme)
+        } else if self.http_uri_str().len() > MAX_HTTP_URI_LEN {
+            Err(UrlTooLong)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The max length of an `http::Uri` scheme.
+const MAX_HTTP_SCHEME_LEN: usize = 64;
+
+/// The max length of an `http::Uri`, which also limits its path & query.
+const MAX_HTTP_URI_LEN: usize = u16::MAX as usize - 1;
+
+impl TryFrom<&Uri> for WebUrl {
+    type Error = Error;
+
+    /// Converts the absolute-form `uri` to a URL. (see [`WebUrl::from_http_request`] for the other request targets)
+    ///
+    /// The URL is normalized like `FromStr`, so the user info is rejected. A `uri` with no scheme is an
+    /// `InvalidScheme` & one with no authority is an `InvalidHost`.
+    fn try_from(uri: &Uri) -> Result<Self, Self::Error> {
+        let scheme: &str = uri.scheme_str().ok_or(InvalidScheme)?;
+        let authority: &Authority = uri.authority().ok_or(InvalidHost)?;
+        let path_and_query: &str = uri.path_and_query().map(PathAndQuery::as_str).unwrap_or_default();
+        from_http_parts(scheme, authority.as_str(), path_and_query)
+    }
+}
+
+impl TryFrom<&WebUrl> for Uri {
+    type Error = Error;
+
+    /// Converts the `url` to a URI. The fragment is dropped since a URI has none.
+    ///
+    /// # Errors
+    /// Returns `InvalidScheme` if the scheme is longer than 64 bytes & `UrlTooLong` if the URL without its fragment is
+    /// longer than 65,534 bytes, which are the limits of a URI.
+    fn try_from(url: &WebUrl) -> Result<Self, Self::Error> {
+        url.check_http_uri_limits()?;
+        Ok(Uri::try_from(url.http_uri_str()).expect("a web-based URL within the limits is a valid URI"))
+    }
+}
+
+impl TryFrom<WebUrl> for Uri {
+    type Error = Error;
+
+    /// Converts the `url` to a URI, reusing its allocation. (see `TryFrom<&WebUrl>`)
+    fn try_from(url: WebUrl) -> Result<Self, Self::Error> {
+        url.check_http_uri_limits()?;
+        let len: usize = url.http_uri_str().len();
+        let mut url: String = url.into();
+        url.truncate(len);
+        Ok(Uri::try_from(url).expect("a web-based URL within the limits is a valid URI"))
+    }
+}
+
+impl From<&WebUrl> for Authority {
+    /// Converts the authority of the `url` to an authority. The user info is included when the URL has it.
+    fn from(url: &WebUrl) -> Self {
+        Authority::try_from(url.authority_str()).expect("a web-based URL authority is a valid authority")
+    }
+}
+
+impl TryFrom<&WebUrl> for PathAndQuery {
+    type Error = Error;
+
+    /// Converts the path & query of the `url` to a path & query.
+    ///
+    /// # Errors
+    /// Returns `UrlTooLong` if the path & query is longer than 65,534 bytes, which is the limit of a URI.
+    fn try_from(url: &WebUrl) -> Result<Self, Self::Error> {
+        let path_and_query: &str = url.path_and_query_str();
+        if path_and_query.len() > MAX_HTTP_URI_LEN {
+            return Err(UrlTooLong);
+        }
+        Ok(PathAndQuery::try_from(path_and_query).expect("a web-based URL path & query is a valid path & query"))
+    }
+}
+
+impl WebUrl {
+    //! HTTP Requests
+
+    /// Creates the URL of an incoming `request`.
+    ///
+    /// The URL is the absolute-form request target when the request has one, as HTTP/2 requests & proxy requests do.
+    /// Otherwise it is the `scheme` the request was received with (`https` over TLS), the `Host` header, & the
+    /// origin-form request target.
+    ///
+    /// The `Host` header is ignored when the target is absolute-form, as
+    /// [RFC 9112](https://www.rfc-editor.org/rfc/rfc9112#section-3.2.2) requires. The URL is normalized like `FromStr`.
+    ///
+    /// # Errors
+    /// Returns `InvalidHost` if there is no authority & no valid `Host` header, & `InvalidPath` if the request target
+    /// is the asterisk-form `*`.
+    pub fn from_http_request<B>(request: &Request<B>, scheme: Scheme) -> Result<Self, Error> {
+        let uri: &Uri = request.uri();
+        let path_and_query: &str = uri.path_and_query().map(PathAndQuery::as_str).unwrap_or_default();
+        if !path_and_query.is_empty() && !path_and_query.starts_with(['/', '?']) {
+            return Err(InvalidPath);
+        }
+        let scheme: &str = uri.scheme_str().unwrap_or(scheme.as_str());
+        let authority: &str = match uri.authority() {
+            Some(authority) => authority.as_str(),
+            None => request
+                .headers()
+                .get(HOST)
+                .and_then(|host| host.to_str().ok())
+                .ok_or(InvalidHost)?,
+        };
+        from_http_parts(scheme, authority, path_and_query)
+    }
+}
+
+/// Parses the URL with the `scheme`, `authority`, & `path_and_query` into one allocation.
+fn from_http_parts(scheme: &str, authority: &str, path_and_query: &str) -> Result<WebUrl, Error> {
+    if authority.is_empty() || authority.contains(['/', '?', '#']) {
+        return Err(InvalidHost);
+    }
+    let mut url: String = String::with_capacity(scheme.len() + 3 + authority.len() + path_and_query.len());
+    url.push_str(scheme);
+    url.push_str("://");
+    url.push_str(authority);
+    url.push_str(path_and_query);
+    WebUrl::try_from(url).map_err(Error::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::{InvalidHost, InvalidPath, InvalidScheme, UrlTooLong, UserInfoNotSupported};
+    use crate::{Error, Scheme, WebUrl};
+    use http::uri::{Authority, PathAndQuery};
+    use http::{Request, Uri};
+    use std::str::FromStr;
+
+    #[test]
+    fn try_from_uri() {
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("https://example.com/a?b", Ok("https://example.com/a?b")),
+            ("HTTP://Example.com:80", Ok("http://example.com/")),
+            ("http://[::1]:8080/a/../b", Ok("http://[::1]:8080/b")),
+            ("http://user@example.com/", Err(UserInfoNotSupported)),
+            ("/a?b", Err(InvalidScheme)),
+            ("example.com:443", Err(InvalidScheme)),
+        ];
+        for (input, expected) in test_cases {
+            let uri: Uri = Uri::from_str(input).unwrap();
+            let result: Result<WebUrl, Error> = WebUrl::try_from(&uri);
+            assert_eq!(
+                result.as_ref().map(WebUrl::as_str),
+                expected.as_deref(),
+                "input={}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn into_uri() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, &str, &str, &str)] = &[
+            ("https://example.com/", "https://example.com/", "example.com", "/"),
+            (
+                "https://example.com:8443/a/b?c=d#e",
+                "https://example.com:8443/a/b?c=d",
+                "example.com:8443",
+                "/a/b?c=d",
+            ),
+            (
+                "http://[::1]/!$'()*,;=:@~?/?",
+                "http://[::1]/!$'()*,;=:@~?/?",
+                "[::1]",
+                "/!$'()*,;=:@~?/?",
+            ),
+        ];
+        for (input, uri, authority, path_and_query) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(Uri::try_from(&url)?, *uri, "input={}", input);
+            assert_eq!(Authority::from(&url), *authority, "input={}", input);
+            assert_eq!(PathAndQuery::try_from(&url)?, *path_and_query, "input={}", input);
+            assert_eq!(Uri::try_from(url)?, *uri, "input={}", input);
+        }
+
+        let url: WebUrl = WebUrl::parse_with_user_info("http://user@example.com/")?;
+        assert_eq!(Authority::from(&url), "user@example.com");
+        Ok(())
+    }
+
+    #[test]
+    fn into_uri_limits() -> Result<(), Box<dyn std::error::Error>> {
+        let path: String = "a".repeat(65_534 - "http://h/".len());
+        let test_cases: &[(String, Result<(), Error>)] = &[
+            (format!("http://h/{}#fragment", path), Ok(())),
+            (format!("http://h/{}a", path), Err(UrlTooLong)),
+            (format!("{}://h/", "s".repeat(64)), Ok(())),
+            (format!("{}://h/", "s".repeat(65)), Err(InvalidScheme)),
+        ];
+        for (input, expected) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            let result: Result<(), Error> = Uri::try_from(&url).map(|_| ());
+            assert_eq!(result, *expected, "input={}", input);
+            let result: Result<(), Error> = Uri::try_from(url).map(|_| ());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+
+        let url: WebUrl = WebUrl::from_str(&format!("http://h/{}", "a".repeat(65_534)))?;
+        assert_eq!(PathAndQuery::try_from(&url).err(), Some(UrlTooLong));
+        Ok(())
+    }
+
+    #[test]
+    fn from_http_request() {
+        let test_cases: &[(&str, Option<&str>, Result<&str, Error>)] = &[
+            ("/a?b", Some("example.com"), Ok("https://example.com/a?b")),
+            ("/", Some("Example.com:443"), Ok("https://example.com/")),
+            ("/", Some("example.com:8443"), Ok("https://example.com:8443/")),
+            (
+                "http://proxy.example.com/a",
+                Some("example.com"),
+                Ok("http://proxy.example.com/a"),
+            ),
+            ("/", None, Err(InvalidHost)),
+            ("/", Some(""), Err(InvalidHost)),
+            ("/", Some("example.com/a"), Err(InvalidHost)),
+            ("/", Some("ex ample.com"), Err(InvalidHost)),
+            ("*", Some("example.com"), Err(InvalidPath)),
+        ];
+        for (target, host, expected) in test_cases {
+            let mut request: http::request::Builder = Request::builder().uri(*target);
+            if let Some(host) = host {
+                request = request.header("host", *host);
+            }
+            let request: Request<()> = request.body(()).unwrap();
+            let result: Result<WebUrl, Error> = WebUrl::from_http_request(&request, Scheme::HTTPS);
+            assert_eq!(
+                result.as_ref().map(WebUrl::as_str),
+                expected.as_deref(),
+                "target={} host={:?}",
+                target,
+                host
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::Error::InvalidScheme;
+use crate::parse::{Reference, parse_reference, write_canonical_path};
+use crate::{Error, Normalizer, WebUrl};
+
+impl WebUrl {
+    //! Join
+
+    /// Resolves the `reference` against this URL.
+    ///
+    /// The reference may be a full URL, a scheme-relative reference (`//host/path`), an absolute path (`/path`), a
+    /// relative path (`../path`), a query (`?query`), a fragment (`#fragment`), or empty. The result is normalized
+    /// exactly like a parsed URL, so its dot-segments are removed.
+    ///
+    /// # Errors
+    /// The resolved URL is validated like a parsed URL, so an invalid reference fails with the error for the invalid
+    /// component. A reference with a scheme but no authority, like `mailto:x` or `http:g`, is not a web-based URL &
+    /// fails with `InvalidScheme`. A reference with user info fails with `UserInfoNotSupported` unless this URL has
+    /// user info.
+    ///
+    /// # RFC 3986
+    /// The reference is resolved with the strict algorithm, so a reference with the same scheme as this URL is still
+    /// resolved as a full URL. <https://www.rfc-editor.org/rfc/rfc3986#section-5.2>
+    ///
+    /// # Example
+    /// Resolving against `"http://a/b/c/d;p?q"`:
+    /// - `"g"` -> `"http://a/b/c/g"`
+    /// - `"../g"` -> `"http://a/b/g"`
+    /// - `"//g"` -> `"http://g/"`
+    /// - `"?y"` -> `"http://a/b/c/d;p?y"`
+    /// - `"#s"` -> `"http://a/b/c/d;p?q#s"`
+    pub fn join(&self, reference: &str) -> Result<WebUrl, Error> {
+        let reference: Reference = parse_reference(reference);
+        let mut url: String = String::with_capacity(self.len() + reference.path.len() + 2);
+
+        // The query is inherited from this URL only when the reference has neither a path nor a query.
+        let mut query: Option<&str> = reference.query;
+
+        if let Some(scheme) = reference.scheme {
+            // The web-based URL format requires an authority, so a reference that is a full URL must have one.
+            let authority: &str = reference.authority.ok_or(InvalidScheme)?;
+            url.push_str(scheme);
+            url.push_str("://");
+            url.push_str(authority);
+            Self::push_reference_path(&mut url, reference.path);
+        } else {
+            url.push_str(self.scheme().as_str());
+            url.push_str("://");
+            if let Some(authority) = reference.authority {
+                url.push_str(authority);
+                Self::push_reference_path(&mut url, reference.path);
+            } else {
+                url.push_str(self.authority_str());
+                if reference.path.is_empty() {
+                    url.push_str(self.path().as_str());
+                    query = query.or(self.query().map(|q| q.as_str()));
+                } else if reference.path.starts_with('/') {
+                    write_canonical_path(reference.path, Normalizer::DEFAULT, &mut url);
+                } else {
+                    // The reference path is merged with every segment of this path except the last. The path always
+                    // starts with a '/', so it always has a '/' to merge at.
+                    let path: &str = self.path().as_str();
+                    let directory: &str = &path[..=path.rfind('/').unwrap_or(0)];
+                    let mut merged: String = String::with_capacity(directory.len() + reference.path.len());
+                    merged.push_str(directory);
+                    merged.push_str(reference.path);
+                    write_canonical_path(merged.as_str(), Normalizer::DEFAULT, &mut url);
+                }
+            }
+        }
+
+        if let Some(query) = query {
+            url.push_str(query);
+        }
+        if let Some(fragment) = reference.fragment {
+            url.push_str(fragment);
+        }
+
+        // The user info is only accepted when this URL has it, so a reference cannot add credentials to a URL that had
+        // none.
+        Normalizer::DEFAULT
+            .with_user_info(self.user_info().is_some())
+            .parse_string(url)
+            .map_err(Error::from)
+    }
+
+    /// Appends the `path` of a reference with an authority to the `url`.
+    ///
+    /// The path follows an authority, so it is empty or starts with a '/'. An empty path is left for the parser to
+    /// fill in with the '/' path.
+    fn push_reference_path(url: &mut String, path: &str) {
+        if path.starts_with('/') {
+            write_canonical_path(path, Normalizer::DEFAULT, url);
+        } else {
+            url.push_str(path);
+        }
+    }
+
+    /// Gets the authority string. (the optional user info, the host, & the optional port)
+    pub(in crate::web_url) fn authority_str(&self) -> &str {
+        let start: usize = (self.scheme_len + 3) as usize;
+        let end: usize = self.port_end as usize;
+        &self.url[start..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::{InvalidHost, InvalidPath, InvalidScheme, UserInfoNotSupported};
+    use crate::{Error, WebUrl};
+    use std::str::FromStr;
+
+    /// The base URL of the RFC 3986 examples.
+    const BASE: &str = "http://a/b/c/d;p?q";
+
+    #[test]
+    fn join_rfc_normal() {
+        // https://www.rfc-editor.org/rfc/rfc3986#section-5.4.1
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            // A reference with a scheme but no authority is not a web-based URL.
+            ("g:h", Err(InvalidScheme)),
+            ("g", Ok("http://a/b/c/g")),
+            ("./g", Ok("http://a/b/c/g")),
+            ("g/", Ok("http://a/b/c/g/")),
+            ("/g", Ok("http://a/g")),
+            // A URL with no explicit path gets the path '/'.
+            ("//g", Ok("http://g/")),
+            ("?y", Ok("http://a/b/c/d;p?y")),
+            ("g?y", Ok("http://a/b/c/g?y")),
+            ("#s", Ok("http://a/b/c/d;p?q#s")),
+            ("g#s", Ok("http://a/b/c/g#s")),
+            ("g?y#s", Ok("http://a/b/c/g?y#s")),
+            (";x", Ok("http://a/b/c/;x")),
+            ("g;x", Ok("http://a/b/c/g;x")),
+            ("g;x?y#s", Ok("http://a/b/c/g;x?y#s")),
+            ("", Ok("http://a/b/c/d;p?q")),
+            (".", Ok("http://a/b/c/")),
+            ("./", Ok("http://a/b/c/")),
+            ("..", Ok("http://a/b/")),
+            ("../", Ok("http://a/b/")),
+            ("../g", Ok("http://a/b/g")),
+            ("../..", Ok("http://a/")),
+            ("../../", Ok("http://a/")),
+            ("../../g", Ok("http://a/g")),
+        ];
+        let base: WebUrl = WebUrl::from_str(BASE).unwrap();
+        for (reference, expected) in test_cases {
+            let result: Result<WebUrl, Error> = base.join(reference);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "reference={}", reference),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "reference={}", reference),
+            }
+        }
+    }
+
+    #[test]
+    fn join_rfc_abnormal() {
+        // https://www.rfc-editor.org/rfc/rfc3986#section-5.4.2
+        let test_cases: &[(&str, Result<&str, Error>)] = &[
+            ("../../../g", Ok("http://a/g")),
+            ("../../../../g", Ok("http://a/g")),
+            ("/./g", Ok("http://a/g")),
+            ("/../g", Ok("http://a/g")),
+            ("g.", Ok("http://a/b/c/g.")),
+            (".g", Ok("http://a/b/c/.g")),
+            ("g..", Ok("http://a/b/c/g..")),
+            ("..g", Ok("http://a/b/c/..g")),
+            ("./../g", Ok("http://a/b/g")),
+            ("./g/.", Ok("http://a/b/c/g/")),
+            ("g/./h", Ok("http://a/b/c/g/h")),
+            ("g/../h", Ok("http://a/b/c/h")),
+            ("g;x=1/./y", Ok("http://a/b/c/g;x=1/y")),
+            ("g;x=1/../y", Ok("http://a/b/c/y")),
+            ("g?y/./x", Ok("http://a/b/c/g?y/./x")),
+            ("g?y/../x", Ok("http://a/b/c/g?y/../x")),
+            ("g#s/./x", Ok("http://a/b/c/g#s/./x")),
+            ("g#s/../x", Ok("http://a/b/c/g#s/../x")),
+            // The strict algorithm resolves a reference with a scheme as a full URL, even when it is this URL's scheme.
+            ("http:g", Err(InvalidScheme)),
+        ];
+        let base: WebUrl = WebUrl::from_str(BASE).unwrap();
+        for (reference, expected) in test_cases {
+            let result: Result<WebUrl, Error> = base.join(reference);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "reference={}", reference),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "reference={}", reference),
+            }
+        }
+    }
+
+    #[test]
+    fn join() {
+        let test_cases: &[(&str, &str, Result<&str, Error>)] = &[
+            // A full URL is normalized like a parsed URL.
+            (
+                "http://a/b",
+                "HTTPS://CDN.example.com:0443/x/../y",
+                Ok("https://cdn.example.com/y"),
+            ),
+            ("http://a/b", "https://c", Ok("https://c/")),
+            // A scheme-relative reference keeps the scheme only.
+            (
+                "https://a:8080/b?q#f",
+                "//cdn.example.com/x",
+                Ok("https://cdn.example.com/x"),
+            ),
+            ("https://a:8080/b?q#f", "//c:9090", Ok("https://c:9090/")),
+            // A relative reference keeps the authority, port included.
+            ("https://a:8080/b/c?q#f", "../img/a.png", Ok("https://a:8080/img/a.png")),
+            ("https://a:8080/b/c?q#f", "?page=2", Ok("https://a:8080/b/c?page=2")),
+            ("https://a:8080/b/c?q#f", "#top", Ok("https://a:8080/b/c?q#top")),
+            ("https://a:8080/b/c?q#f", "", Ok("https://a:8080/b/c?q")),
+            ("http://[::1]/b/c", "d", Ok("http://[::1]/b/d")),
+            // A base path ending with a '/' merges below it.
+            ("http://a/b/", "c", Ok("http://a/b/c")),
+            ("http://a/", "..", Ok("http://a/")),
+            // An empty query or fragment is still a query or fragment.
+            ("http://a/b?q#f", "?", Ok("http://a/b?")),
+            ("http://a/b?q#f", "#", Ok("http://a/b?q#")),
+            // The resolved URL is validated like a parsed URL.
+            ("http://a/b", "g h", Err(InvalidPath)),
+            ("http://a/b", "//", Err(InvalidHost)),
+            ("http://a/b", "//user@c/", Err(UserInfoNotSupported)),
+            ("http://a/b", "1http://c/", Err(InvalidScheme)),
+            ("http://a/b", "mailto:user@example.com", Err(InvalidScheme)),
+        ];
+        for (base, reference, expected) in test_cases {
+            let base: WebUrl = WebUrl::from_str(base).unwrap();
+            let result: Result<WebUrl, Error> = base.join(reference);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "reference={}", reference),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "reference={}", reference),
+            }
+        }
+    }
+
+    #[test]
+    fn join_user_info() {
+        let test_cases: &[(&str, &str, Result<&str, Error>)] = &[
+            // A relative reference keeps the user info.
+            ("http://u:p@a/b/c", "d?q", Ok("http://u:p@a/b/d?q")),
+            ("http://u:p@a/b/c", "#f", Ok("http://u:p@a/b/c#f")),
+            // A reference with an authority replaces it whole.
+            ("http://u:p@a/b/c", "//a/d", Ok("http://a/d")),
+            ("http://u:p@a/b/c", "//v@a/d", Ok("http://v@a/d")),
+            ("http://u:p@a/b/c", "https://v@c/", Ok("https://v@c/")),
+            // A reference cannot add user info to a URL without it.
+            ("http://a/b/c", "//v@a/d", Err(UserInfoNotSupported)),
+            ("http://a/b/c", "https://v@c/", Err(UserInfoNotSupported)),
+        ];
+        for (base, reference, expected) in test_cases {
+            let base: WebUrl = WebUrl::parse_with_user_info(base).unwrap();
+            let result: Result<WebUrl, Error> = base.join(reference);
+            match expected {
+                Ok(expected) => assert_eq!(result.unwrap().as_str(), *expected, "reference={}", reference),
+                Err(expected) => assert_eq!(result.unwrap_err(), *expected, "reference={}", reference),
+            }
+        }
+    }
+
+    #[test]
+    fn authority_str() {
+        let url: WebUrl = WebUrl::from_str("https://a:8080/b").unwrap();
+        assert_eq!(url.authority_str(), "a:8080");
+
+        let url: WebUrl = WebUrl::from_str("https://[::1]/b").unwrap();
+        assert_eq!(url.authority_str(), "[::1]");
+
+        let url: WebUrl = WebUrl::parse_with_user_info("https://u:p@a/b").unwrap();
+        assert_eq!(url.authority_str(), "u:p@a");
+    }
+}
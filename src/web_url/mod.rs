@@ -1,13 +1,30 @@
+pub use static_web_url::*;
 pub use web_url::*;
+pub use web_url_ref::*;
 
+mod static_web_url;
 mod web_url;
+mod web_url_ref;
 
 mod compare;
 mod display;
 mod fragment;
 mod host;
+#[cfg(feature = "http")]
+mod http;
+mod join;
+mod origin;
 mod param;
 mod path;
 mod port;
+#[cfg(feature = "psl")]
+mod public_suffix;
 mod query;
+#[cfg(feature = "serde")]
+mod query_serde;
+mod redacted;
+mod relative;
 mod scheme;
+#[cfg(feature = "url")]
+mod url;
+mod user_info;
@@ -0,0 +1,17 @@
+use crate::{Origin, TupleOrigin, WebUrl};
+
+impl WebUrl {
+    //! Origin
+
+    /// Gets the origin.
+    ///
+    /// A URL with a special scheme has a tuple origin of its scheme, host, & effective port. Any other URL has an
+    /// opaque origin. (see [`Origin`])
+    #[must_use]
+    pub fn origin(&self) -> Origin {
+        match TupleOrigin::new(self.scheme(), self.host(), self.port()) {
+            Some(tuple) => Origin::Tuple(tuple),
+            None => Origin::Opaque,
+        }
+    }
+}
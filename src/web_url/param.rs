@@ -1,4 +1,6 @@
+use crate::parse::{normalize_percent, percent_normalized_len, write_percent_normalized};
 use crate::{Param, WebUrl};
+use std::borrow::Cow;
 
 impl WebUrl {
     //! Query Parameter Mutations
@@ -17,6 +19,8 @@ impl WebUrl {
     /// - `"/?a=2"` -> `"/?a=2&p=1"`
     /// - `"/?&"` -> `"/?&&p=1"`
     ///
+    /// The percent-encoding of the param is normalized, so `p=%7e` is added as `p=~`.
+    ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn add_param(&mut self, param: Param) {
@@ -63,6 +67,10 @@ impl WebUrl {
     /// - `"/?a=1&b=2"` -> `"/?b=2"`
     /// - `"/?a=1&b=2&a=3"` -> `"/?b=2"`
     pub fn remove_params(&mut self, name: &str) -> usize {
+        // The params in the URL have normalized percent-encoding, so the name is normalized to compare equal.
+        let name: Cow<str> = normalize_percent(name);
+        let name: &str = name.as_ref();
+
         // The query is scanned before it is rebuilt so that a URL without a matching param is left untouched & never
         // allocates.
         if !self.query().into_iter().flatten().any(|p| p.name() == name) {
@@ -104,10 +112,13 @@ impl WebUrl {
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn replace_params(&mut self, param: Param) -> usize {
+        // The params in the URL have normalized percent-encoding, so the name is normalized to compare equal.
+        let name: Cow<str> = normalize_percent(param.name());
+
         let mut replaced: usize = 0;
         let mut query: String = String::with_capacity(self.query_len());
         for existing in self.query().into_iter().flatten() {
-            if existing.name() == param.name() {
+            if existing.name() == name {
                 replaced += 1;
                 if replaced == 1 {
                     Self::push_query_param(&mut query, param);
@@ -137,19 +148,20 @@ impl WebUrl {
 
     /// Appends the `separator` & the `param` to the `out` string.
     ///
-    /// This is the only place a param is spelled out, so `push_param_len` must match what it writes.
+    /// This is the only place a param is spelled out, so `push_param_len` must match what it writes. The
+    /// percent-encoding of the param is normalized as it is written.
     fn push_param(out: &mut String, separator: char, param: Param) {
         out.push(separator);
-        out.push_str(param.name());
+        write_percent_normalized(param.name(), out);
         if let Some(value) = param.value() {
             out.push('=');
-            out.push_str(value);
+            write_percent_normalized(value, out);
         }
     }
 
     /// Gets the number of bytes `push_param` appends for the `param`. (including its separator)
     fn push_param_len(param: Param) -> usize {
-        1 + param.name().len() + param.value().map(|v| 1 + v.len()).unwrap_or(0)
+        1 + percent_normalized_len(param.name()) + param.value().map(|v| 1 + percent_normalized_len(v)).unwrap_or(0)
     }
 
     /// Appends the `param` to the `query` string being rebuilt, with its separator.
@@ -305,6 +317,9 @@ mod tests {
             ("https://host/p?", "", 1, "https://host/p"),
             ("https://host/p?&", "", 2, "https://host/p"),
             ("https://host/p?&a=1", "", 1, "https://host/p?a=1"),
+            // The name is compared with its percent-encoding normalized.
+            ("https://host/p?%7ea=1&b=2", "%7Ea", 1, "https://host/p?b=2"),
+            ("https://host/p?%2fa=1&b=2", "%2fa", 1, "https://host/p?b=2"),
         ];
         for (input, name, removed, expected) in test_cases {
             let mut url: WebUrl = WebUrl::from_str(input)?;
@@ -340,6 +355,9 @@ mod tests {
             // A query that is just a '?' is still one empty param.
             ("https://host/p?", "a=9", 0, "https://host/p?&a=9"),
             ("https://host/p?", "", 1, "https://host/p?"),
+            // The percent-encoding of the replacement is normalized.
+            ("https://host/p?~a=1", "%7ea=%2f", 1, "https://host/p?~a=%2F"),
+            ("https://host/p", "%7ea=%2f", 0, "https://host/p?~a=%2F"),
         ];
         for (input, param, replaced, expected) in test_cases {
             let mut url: WebUrl = WebUrl::from_str(input)?;
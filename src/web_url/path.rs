@@ -1,5 +1,5 @@
 use crate::parse;
-use crate::{Path, WebUrl};
+use crate::{Normalizer, Path, WebUrl};
 
 impl WebUrl {
     //! Path
@@ -11,7 +11,8 @@ impl WebUrl {
 
     /// Gets the path string.
     ///
-    /// This will be a valid path starting with a '/' & having no dot-segments.
+    /// This will be a valid path starting with a '/'. It has no dot-segments unless the URL was parsed with a
+    /// `Normalizer` that keeps them.
     fn path_str(&self) -> &str {
         let start: usize = self.port_end as usize;
         let end: usize = self.path_end as usize;
@@ -22,14 +23,18 @@ impl WebUrl {
 impl WebUrl {
     //! Path Mutation
 
-    /// Sets the `path`. (the dot-segments are removed)
+    /// Sets the `path`. (the dot-segments are removed & the percent-encoding is normalized)
+    ///
+    /// The `path` is always normalized with [`Normalizer::DEFAULT`], whichever normalizer the URL was parsed with, so
+    /// the dot-segments are removed even from a URL parsed with [`Normalizer::MINIMAL`].
     ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn set_path(&mut self, path: Path) {
-        // The path is written with the dot-segments removed, which is the normalized form.
-        let mut insert: String = String::with_capacity(parse::canonical_path_len(path.as_str()));
-        parse::write_canonical_path(path.as_str(), &mut insert);
+        // The path is written with the dot-segments removed & the percent-encoding normalized, which is the normalized
+        // form.
+        let mut insert: String = String::with_capacity(parse::canonical_path_len(path.as_str(), Normalizer::DEFAULT));
+        parse::write_canonical_path(path.as_str(), Normalizer::DEFAULT, &mut insert);
 
         let start: usize = self.port_end as usize;
         let end: usize = self.path_end as usize;
@@ -50,7 +55,7 @@ impl WebUrl {
         debug_assert!(self.is_consistent());
     }
 
-    /// Sets the `path`. (the dot-segments are removed)
+    /// Sets the `path`. (see `set_path`)
     ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`.
@@ -62,7 +67,7 @@ impl WebUrl {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Path, WebUrl};
+    use crate::{Normalizer, Path, WebUrl};
     use std::error::Error;
     use std::str::FromStr;
 
@@ -92,6 +97,7 @@ mod tests {
             ("http://host/", "/a/./b/", "http://host/a/b/"),
             ("http://host/", "/..", "http://host/"),
             ("http://host:8080/old?q", "/new", "http://host:8080/new?q"),
+            ("http://host/", "/%7e/%2e%2e/a%2fb", "http://host/a%2Fb"),
         ];
         for (input, path, expected) in test_cases {
             let mut url: WebUrl = WebUrl::from_str(input)?;
@@ -99,6 +105,12 @@ mod tests {
             assert_eq!(url.as_str(), *expected, "input={} path={}", input, path);
         }
 
+        // The path is normalized with the default normalizer even when the URL was parsed to keep the dot-segments.
+        let mut url: WebUrl = Normalizer::MINIMAL.parse("http://host/a/../b")?;
+        assert_eq!(url.as_str(), "http://host/a/../b");
+        url.set_path(Path::try_from("/c/../d")?);
+        assert_eq!(url.as_str(), "http://host/d");
+
         Ok(())
     }
 
@@ -9,6 +9,15 @@ impl WebUrl {
     pub fn port(&self) -> Option<u16> {
         self.port
     }
+
+    /// Gets the effective port. (the port, or the default port of the scheme when there is no port)
+    ///
+    /// This is the port that would be connected to. It is `None` only when there is no port & the scheme has no
+    /// default port.
+    #[must_use]
+    pub fn effective_port(&self) -> Option<u16> {
+        self.port.or_else(|| self.scheme().default_port())
+    }
 }
 
 impl WebUrl {
@@ -16,13 +25,18 @@ impl WebUrl {
 
     /// Sets the optional `port`.
     ///
+    /// The default port of the scheme is elided, so setting the port 80 on an `http` URL removes the port. This is
+    /// [`Normalizer::DEFAULT`](crate::Normalizer::DEFAULT) behavior & applies whichever normalizer the URL was parsed
+    /// with, even one that keeps the default port.
+    ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn set_port<P>(&mut self, port: P)
     where
         P: Into<Option<u16>>,
     {
-        let port: Option<u16> = port.into();
+        let default_port: Option<u16> = self.scheme().default_port();
+        let port: Option<u16> = port.into().filter(|port| Some(*port) != default_port);
 
         // The port is written with its ':' prefix & without leading zeros, which is the normalized form. A URL with no
         // port has no ':' either.
@@ -72,7 +86,7 @@ impl WebUrl {
 
 #[cfg(test)]
 mod tests {
-    use crate::WebUrl;
+    use crate::{Normalizer, WebUrl};
     use std::error::Error;
     use std::str::FromStr;
 
@@ -90,25 +104,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn effective_port() -> Result<(), Box<dyn Error>> {
+        let test_cases: &[(&str, Option<u16>, Option<u16>)] = &[
+            ("http://host/", None, Some(80)),
+            ("http://host:80/", None, Some(80)),
+            ("http://host:8080/", Some(8080), Some(8080)),
+            ("https://host/", None, Some(443)),
+            ("ws://host/", None, Some(80)),
+            ("wss://host/", None, Some(443)),
+            ("ftp://host/", None, Some(21)),
+            ("ftp://host:0021/", None, Some(21)),
+            ("s://host/", None, None),
+            ("s://host:80/", Some(80), Some(80)),
+        ];
+        for (input, port, effective_port) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(url.port(), *port, "input={}", input);
+            assert_eq!(url.effective_port(), *effective_port, "input={}", input);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn set_port() -> Result<(), Box<dyn Error>> {
         // The port changes length, so the path, query, & fragment offsets must shift with it.
-        let test_cases: &[(&str, Option<u16>, &str)] = &[
-            ("http://host/p?q#f", Some(8080), "http://host:8080/p?q#f"),
-            ("http://host:80/p?q#f", Some(443), "http://host:443/p?q#f"),
-            ("http://host:80/p?q#f", None, "http://host/p?q#f"),
-            ("http://host/p", None, "http://host/p"),
-            ("http://host/p", Some(0), "http://host:0/p"),
-            ("http://host:1/p", Some(65535), "http://host:65535/p"),
-            ("http://[::1]/p", Some(80), "http://[::1]:80/p"),
+        let test_cases: &[(&str, Option<u16>, &str, Option<u16>)] = &[
+            ("http://host/p?q#f", Some(8080), "http://host:8080/p?q#f", Some(8080)),
+            ("http://host:80/p?q#f", Some(443), "http://host:443/p?q#f", Some(443)),
+            ("http://host:80/p?q#f", None, "http://host/p?q#f", None),
+            ("http://host/p", None, "http://host/p", None),
+            ("http://host/p", Some(0), "http://host:0/p", Some(0)),
+            ("http://host:1/p", Some(65535), "http://host:65535/p", Some(65535)),
+            ("http://[::1]/p", Some(443), "http://[::1]:443/p", Some(443)),
+            // The default port of the scheme is elided.
+            ("http://host:8080/p?q#f", Some(80), "http://host/p?q#f", None),
+            ("https://[::1]/p", Some(443), "https://[::1]/p", None),
+            ("s://host/p", Some(80), "s://host:80/p", Some(80)),
         ];
-        for (input, port, expected) in test_cases {
+        for (input, port, expected, expected_port) in test_cases {
             let mut url: WebUrl = WebUrl::from_str(input)?;
             url.set_port(*port);
             assert_eq!(url.as_str(), *expected, "input={}", input);
-            assert_eq!(url.port(), *port, "input={}", input);
+            assert_eq!(url.port(), *expected_port, "input={}", input);
+            let effective_port: Option<u16> = port.or(url.scheme().default_port());
+            assert_eq!(url.effective_port(), effective_port, "input={}", input);
         }
 
+        // The default port is elided even when the URL was parsed to keep it.
+        let mut url: WebUrl = Normalizer::MINIMAL.parse("http://host:80/p")?;
+        assert_eq!(url.as_str(), "http://host:80/p");
+        url.set_port(80);
+        assert_eq!(url.as_str(), "http://host/p");
+
         Ok(())
     }
 
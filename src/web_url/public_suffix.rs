@@ -0,0 +1,78 @@
+use crate::{PublicSuffixList, Site, WebUrl};
+use address::{DomainRef, HostRef};
+
+impl WebUrl {
+    //! Public Suffix
+
+    /// Gets the public suffix of the domain host with the embedded Public Suffix List.
+    /// (`https://www.example.co.uk/` -> `co.uk`)
+    ///
+    /// Returns `None` if the host is an IP address. (see [`PublicSuffixList::public_suffix`])
+    #[must_use]
+    pub fn public_suffix(&self) -> Option<DomainRef<'_>> {
+        match self.host() {
+            HostRef::Name(domain) => Some(PublicSuffixList::EMBEDDED.public_suffix(domain)),
+            HostRef::Address(_) => None,
+        }
+    }
+
+    /// Gets the registrable domain of the domain host with the embedded Public Suffix List. (the eTLD+1)
+    /// (`https://www.example.co.uk/` -> `example.co.uk`)
+    ///
+    /// Returns `None` if the host is an IP address or a public suffix.
+    /// (see [`PublicSuffixList::registrable_domain`])
+    #[must_use]
+    pub fn registrable_domain(&self) -> Option<DomainRef<'_>> {
+        match self.host() {
+            HostRef::Name(domain) => PublicSuffixList::EMBEDDED.registrable_domain(domain),
+            HostRef::Address(_) => None,
+        }
+    }
+
+    /// Gets the site with the embedded Public Suffix List. (see [`Site`])
+    #[must_use]
+    pub fn site(&self) -> Site {
+        self.origin().site()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WebUrl;
+    use address::DomainRef;
+    use std::str::FromStr;
+
+    #[test]
+    fn public_suffix() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, Option<&str>, Option<&str>)] = &[
+            ("https://www.example.com/", Some("com"), Some("example.com")),
+            ("https://www.example.co.uk/", Some("co.uk"), Some("example.co.uk")),
+            (
+                "https://example.github.io/",
+                Some("github.io"),
+                Some("example.github.io"),
+            ),
+            ("https://co.uk/", Some("co.uk"), None),
+            ("https://localhost/", Some("localhost"), None),
+            ("https://127.0.0.1/", None, None),
+            ("https://[::1]/", None, None),
+            #[cfg(feature = "idna")]
+            (
+                "https://www.例子.公司.cn/",
+                Some("xn--55qx5d.cn"),
+                Some("xn--fsqu00a.xn--55qx5d.cn"),
+            ),
+        ];
+        for (input, suffix, registrable) in test_cases {
+            let url: WebUrl = WebUrl::from_str(input)?;
+            assert_eq!(url.public_suffix().map(DomainRef::name), *suffix, "input={}", input);
+            assert_eq!(
+                url.registrable_domain().map(DomainRef::name),
+                *registrable,
+                "input={}",
+                input
+            );
+        }
+        Ok(())
+    }
+}
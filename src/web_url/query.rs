@@ -1,3 +1,4 @@
+use crate::parse::normalize_percent;
 use crate::{Query, WebUrl};
 
 impl WebUrl {
@@ -34,16 +35,17 @@ impl WebUrl {
 
     /// Sets the optional `query`.
     ///
+    /// The percent-encoding of the `query` is normalized, so `?%7e` is set as `?~`.
+    ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn set_query<'a, Q>(&mut self, query: Q)
     where
         Q: Into<Option<Query<'a>>>,
     {
-        // The query is preserved exactly, so a query string is already the normalized form. A URL with no query has no
-        // '?' either.
+        // Only the percent-encoding of a query string is normalized. A URL with no query has no '?' either.
         let query: Option<Query> = query.into();
-        self.set_query_str(query.map(Query::as_str).unwrap_or(""));
+        self.set_query_str(normalize_percent(query.map(Query::as_str).unwrap_or("")).as_ref());
     }
 
     /// Sets the optional `query`.
@@ -116,6 +118,11 @@ mod tests {
             assert_eq!(url.query().map(Query::as_str), *query, "input={}", input);
         }
 
+        // The percent-encoding of the query is normalized.
+        let mut url: WebUrl = WebUrl::from_str("http://host/p#f")?;
+        url.set_query(Query::try_from("?%7e=%2f")?);
+        assert_eq!(url.as_str(), "http://host/p?~=%2F#f");
+
         Ok(())
     }
 
@@ -0,0 +1,113 @@
+use crate::{Query, QuerySerdeError, WebUrl};
+use serde::{Deserialize, Serialize};
+
+impl WebUrl {
+    //! Typed Query
+
+    /// Deserializes the query params into a typed value. (see [`Query::deserialize_into`])
+    ///
+    /// A URL with no query is deserialized like an empty query, so a type with only `Option` & defaulted fields can
+    /// still be deserialized.
+    ///
+    /// # Errors
+    /// Returns an error if the params do not match the type.
+    pub fn query_as<'a, T>(&'a self) -> Result<T, QuerySerdeError>
+    where
+        T: Deserialize<'a>,
+    {
+        self.query().unwrap_or_default().deserialize_into()
+    }
+
+    /// Sets the query to the serialized `value`.
+    ///
+    /// The value is serialized with the `application/x-www-form-urlencoded` semantics: a space is encoded as a '+'
+    /// char, a `Vec` field is a repeated name, & a `None` field is skipped. Every char other than the letters, digits,
+    /// & `*-._` is percent-encoded, then the query is normalized like any set query, so the escapes of the unreserved
+    /// chars are decoded. (a '~' is not encoded) The query is removed when there are no params.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a struct or map of scalar & sequence values. The URL is left unmodified.
+    ///
+    /// # Panics
+    /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
+    pub fn set_query_from<T>(&mut self, value: &T) -> Result<(), QuerySerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut query: String = String::from("?");
+        serde_html_form::push_to_string(&mut query, value).map_err(QuerySerdeError::new)?;
+        if query.len() == 1 {
+            self.set_query(None);
+        } else {
+            // The serializer percent-encodes every char that is not valid in a query, so the query is valid.
+            self.set_query(unsafe { Query::new_unchecked(query.as_str()) });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WebUrl;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    struct Search {
+        q: Option<String>,
+        #[serde(default)]
+        tag: Vec<String>,
+    }
+
+    #[test]
+    fn query_as() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(&str, Option<&str>, &[&str])] = &[
+            ("https://example.com/", None, &[]),
+            ("https://example.com/?", None, &[]),
+            ("https://example.com/?q=a+b&tag=x&tag=y#q=c", Some("a b"), &["x", "y"]),
+        ];
+        for (input, q, tag) in test_cases {
+            let search: Search = WebUrl::from_str(input)?.query_as()?;
+            assert_eq!(search.q.as_deref(), *q, "input={}", input);
+            assert_eq!(search.tag, *tag, "input={}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn set_query_from() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases: &[(Option<&str>, &[&str], &str)] = &[
+            (Some("rust"), &[], "https://example.com/p?q=rust#f"),
+            (Some("a b&c=d?"), &[], "https://example.com/p?q=a+b%26c%3Dd%3F#f"),
+            (
+                Some("✓"),
+                &["x", "y z"],
+                "https://example.com/p?q=%E2%9C%93&tag=x&tag=y+z#f",
+            ),
+            (None, &["x"], "https://example.com/p?tag=x#f"),
+            (Some("~"), &[], "https://example.com/p?q=~#f"),
+            (None, &[], "https://example.com/p#f"),
+        ];
+        for (q, tag, expected) in test_cases {
+            let search: Search = Search {
+                q: q.map(str::to_string),
+                tag: tag.iter().map(|tag| tag.to_string()).collect(),
+            };
+            let mut url: WebUrl = WebUrl::from_str("https://example.com/p?old=1#f")?;
+            url.set_query_from(&search)?;
+            assert_eq!(url.as_str(), *expected, "q={:?} tag={:?}", q, tag);
+            assert_eq!(url.query_as::<Search>()?, search, "q={:?} tag={:?}", q, tag);
+        }
+
+        let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+        map.insert("page", 2);
+        let mut url: WebUrl = WebUrl::from_str("https://example.com/")?;
+        url.set_query_from(&map)?;
+        assert_eq!(url.as_str(), "https://example.com/?page=2");
+
+        assert!(url.set_query_from(&42).is_err());
+        assert_eq!(url.as_str(), "https://example.com/?page=2");
+        Ok(())
+    }
+}
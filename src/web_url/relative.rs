@@ -0,0 +1,246 @@
+use crate::{Normalizer, WebUrl};
+
+impl WebUrl {
+    //! Relative
+
+    /// Creates the shortest reference that resolves against this URL to the `target` URL.
+    ///
+    /// This is the inverse of [`WebUrl::join`]: `self.join(reference)` gives back the `target` for the returned
+    /// reference. The reference is the shortest of a fragment-only (`#fragment`), query-only (`?query`), relative path
+    /// (`../path`), absolute path (`/path`), & scheme-relative (`//host/path`) reference. The empty reference is
+    /// returned when the `target` is this URL without its fragment.
+    ///
+    /// Returns `None` if the schemes differ, since a reference without a scheme always keeps the scheme of this URL. It
+    /// also returns `None` if the `target` has user info & this URL does not, since `join` would reject it, & if either
+    /// URL is not normalized with [`Normalizer::DEFAULT`], like a URL parsed with [`Normalizer::MINIMAL`] that kept its
+    /// dot-segments, since `join` normalizes the resolved URL with it.
+    ///
+    /// # Example
+    /// From `"http://a/b/c/d;p?q"`:
+    /// - `"http://a/b/c/g"` -> `"g"`
+    /// - `"http://a/b/g"` -> `"../g"`
+    /// - `"http://a/b/c/d;p?y"` -> `"?y"`
+    /// - `"http://g/x"` -> `"//g/x"`
+    /// - `"https://a/b"` -> `None`
+    #[must_use]
+    pub fn make_relative(&self, target: &WebUrl) -> Option<String> {
+        if self.scheme() != target.scheme() || (target.user_info().is_some() && self.user_info().is_none()) {
+            return None;
+        }
+        if !Self::is_join_normalized(self) || !Self::is_join_normalized(target) {
+            return None;
+        }
+
+        let query: &str = target.query().map(|q| q.as_str()).unwrap_or_default();
+        let fragment: &str = target.fragment().map(|f| f.as_str()).unwrap_or_default();
+
+        if self.authority_str() != target.authority_str() {
+            // The scheme-relative reference is the only reference that changes the authority.
+            return Some(format!("//{}", &target.as_str()[(target.scheme_len + 3) as usize..]));
+        }
+
+        let mut candidates: Vec<String> = Vec::with_capacity(3);
+
+        if self.path() == target.path() {
+            if self.query() == target.query() {
+                // The fragment of this URL is never inherited, so the empty reference drops it.
+                return Some(fragment.to_string());
+            }
+            if target.query().is_some() {
+                candidates.push(format!("{}{}", query, fragment));
+            }
+        }
+
+        candidates.push(format!("{}{}{}", Self::relative_path(self, target), query, fragment));
+
+        // A path starting with "//" would be taken as an authority.
+        let path: &str = target.path().as_str();
+        if !path.starts_with("//") {
+            candidates.push(format!("{}{}{}", path, query, fragment));
+        }
+
+        candidates.into_iter().min_by_key(|c| c.len())
+    }
+
+    /// Checks if the `url` is normalized like the URLs `join` resolves.
+    fn is_join_normalized(url: &WebUrl) -> bool {
+        Normalizer::DEFAULT.with_user_info(true).parse_ref(url.as_str()).is_ok()
+    }
+
+    /// Creates the relative path reference from the path of the `base` to the path of the `target`.
+    ///
+    /// The reference is never empty, since the empty reference keeps the query of the `base`.
+    fn relative_path(base: &WebUrl, target: &WebUrl) -> String {
+        // The last segment of the base path is replaced when merging, so only its directory segments are shared.
+        let mut base_segments: Vec<&str> = base.path().iter_segments().collect();
+        base_segments.pop();
+        let target_segments: Vec<&str> = target.path().iter_segments().collect();
+
+        let common: usize = base_segments
+            .iter()
+            .zip(&target_segments[..target_segments.len() - 1])
+            .take_while(|(b, t)| b == t)
+            .count();
+        let parents: usize = base_segments.len() - common;
+        let remaining: &[&str] = &target_segments[common..];
+
+        let mut reference: String = String::new();
+        if remaining.len() == 1 && remaining[0].is_empty() {
+            // The target is a directory of the base, so the trailing '/' of the last "../" is not needed.
+            if parents == 0 {
+                reference.push('.');
+            } else {
+                for _ in 1..parents {
+                    reference.push_str("../");
+                }
+                reference.push_str("..");
+            }
+            return reference;
+        }
+
+        for _ in 0..parents {
+            reference.push_str("../");
+        }
+        if parents == 0 && (remaining[0].is_empty() || remaining[0].contains(':')) {
+            // A leading empty segment would make the reference an absolute path & a leading segment with a ':' would
+            // be taken as a scheme.
+            reference.push_str("./");
+        }
+        for (i, segment) in remaining.iter().enumerate() {
+            if i != 0 {
+                reference.push('/');
+            }
+            reference.push_str(segment);
+        }
+        reference
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Normalizer, WebUrl};
+    use std::error::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn make_relative() -> Result<(), Box<dyn Error>> {
+        let test_cases: &[(&str, &str, Option<&str>)] = &[
+            // RFC 3986 §5.4.1 in reverse.
+            ("http://a/b/c/d;p?q", "http://a/b/c/g", Some("g")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/g/", Some("g/")),
+            ("http://a/b/c/d;p?q", "http://a/g", Some("/g")),
+            ("http://a/b/c/d;p?q", "http://g/", Some("//g/")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/d;p?y", Some("?y")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/g?y", Some("g?y")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/d;p?q#s", Some("#s")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/g#s", Some("g#s")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/;x", Some(";x")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/d;p?q", Some("")),
+            ("http://a/b/c/d;p?q", "http://a/b/c/", Some(".")),
+            ("http://a/b/c/d;p?q", "http://a/b/", Some("..")),
+            ("http://a/b/c/d;p?q", "http://a/b/g", Some("../g")),
+            ("http://a/b/c/d;p?q", "http://a/", Some("/")),
+            // The fragment of the base is never inherited.
+            ("http://a/b?q#f", "http://a/b?q", Some("")),
+            ("http://a/b?q#f", "http://a/b?q#g", Some("#g")),
+            // Dropping the query needs a path.
+            ("http://a/b/c?q", "http://a/b/c", Some("c")),
+            ("http://a/b/?q", "http://a/b/", Some(".")),
+            ("http://a/b?q", "http://a/b?", Some("?")),
+            // The shorter of the relative & absolute paths wins.
+            ("http://a/b/c/d/e", "http://a/x", Some("/x")),
+            ("http://a/b/c/d/e", "http://a/b/c/d/x", Some("x")),
+            ("http://a/b/c/d/e", "http://a/b/c/x", Some("../x")),
+            ("http://a/b/c/d/e", "http://a/b/x/y", Some("/b/x/y")),
+            ("http://a/long/name/e", "http://a/long/x/y", Some("../x/y")),
+            ("http://a/long/name/e", "http://a/long/", Some("..")),
+            ("http://a/long/name/deeper/e", "http://a/long/", Some("../..")),
+            // A leading empty or ':' segment is protected.
+            ("http://a/b/c", "http://a/b//x", Some(".//x")),
+            ("http://a//c", "http://a//x", Some("x")),
+            ("http://a/b/c", "http://a/b/g:h", Some("./g:h")),
+            ("http://a/b/c", "http://a//", Some("..//")),
+            // A different authority needs a scheme-relative reference.
+            ("https://a/b", "https://a:8080/b", Some("//a:8080/b")),
+            ("https://a/b", "https://c/b?q#f", Some("//c/b?q#f")),
+            ("https://[::1]/b", "https://[::2]/b", Some("//[::2]/b")),
+            // The user info is part of the authority.
+            ("https://u@a/b/c", "https://u@a/b/d", Some("d")),
+            ("https://u@a/b/c", "https://a/b/d", Some("//a/b/d")),
+            ("https://u@a/b/c", "https://v@a/b/d", Some("//v@a/b/d")),
+            // A different scheme cannot be expressed.
+            ("https://a/b", "http://a/b", None),
+            ("https://a/b", "wss://c/b", None),
+            // Nor can user info when the base has none.
+            ("https://a/b", "https://u@a/b", None),
+        ];
+        for (base, target, expected) in test_cases {
+            let base: WebUrl = WebUrl::parse_with_user_info(base)?;
+            let target: WebUrl = WebUrl::parse_with_user_info(target)?;
+            let result: Option<String> = base.make_relative(&target);
+            assert_eq!(result.as_deref(), *expected, "base={} target={}", base, target);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn make_relative_round_trip() -> Result<(), Box<dyn Error>> {
+        let urls: &[&str] = &[
+            "http://a/",
+            "http://a//",
+            "http://a/b",
+            "http://a/b/",
+            "http://a/b//",
+            "http://a/b/c",
+            "http://a/b/c/",
+            "http://a/b/c/d;p?q",
+            "http://a/b/c/d;p?q#f",
+            "http://a/b/c/d;p?",
+            "http://a/b/c/d;p#",
+            "http://a/b/g:h",
+            "http://a/b//x/y",
+            "http://a/x/y/z?q",
+            "http://a:8080/b/c",
+            "http://g/",
+            "http://[::1]/b/c",
+        ];
+        for base in urls {
+            let base: WebUrl = WebUrl::from_str(base)?;
+            for target in urls {
+                let target: WebUrl = WebUrl::from_str(target)?;
+                let reference: String = base.make_relative(&target).unwrap();
+                let resolved: WebUrl = base.join(reference.as_str())?;
+                assert_eq!(resolved, target, "base={} reference={}", base, reference);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn make_relative_not_normalized() -> Result<(), Box<dyn Error>> {
+        let test_cases: &[(&str, &str, Option<&str>)] = &[
+            ("http://a/b", "http://a/y", Some("y")),
+            ("http://a/b", "http://a/x/../y", None),
+            ("http://a/b", "http://a/%7e", None),
+            ("http://a/b", "http://a:80/y", None),
+            ("http://a/long/../y/z", "http://a/long/q", None),
+            ("http://a/long/../y/z", "http://a/y/q", None),
+        ];
+        for (base, target, expected) in test_cases {
+            let base: WebUrl = Normalizer::MINIMAL.parse(base)?;
+            let target: WebUrl = Normalizer::MINIMAL.parse(target)?;
+            let result: Option<String> = base.make_relative(&target);
+            assert_eq!(result.as_deref(), *expected, "base={} target={}", base, target);
+            if let Some(reference) = result {
+                assert_eq!(
+                    base.join(reference.as_str())?,
+                    target,
+                    "base={} target={}",
+                    base,
+                    target
+                );
+            }
+        }
+        Ok(())
+    }
+}
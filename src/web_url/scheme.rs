@@ -22,6 +22,8 @@ impl WebUrl {
 
     /// Sets the `scheme`.
     ///
+    /// The port is removed when it is the default port of the new `scheme`.
+    ///
     /// # Panics
     /// Panics if the resulting URL would exceed `WebUrl::MAX_LEN`. The URL is left unmodified.
     pub fn set_scheme(&mut self, scheme: Scheme) {
@@ -30,13 +32,27 @@ impl WebUrl {
 
         let end: usize = self.scheme_len as usize;
 
+        // The port is elided when it becomes the default port. It is removed before the scheme is spliced in since the
+        // URL is still normalized for the old scheme without its port.
+        let elide_port: bool = self.port.is_some() && self.port == scheme.default_port();
+        let port_len: usize = if elide_port {
+            (self.port_end - self.host_end) as usize
+        } else {
+            0
+        };
+
         // The length is checked before anything is modified so an over-long URL panics with the URL intact rather than
         // leaving the string inconsistent with the component offsets.
-        Self::check_len((self.url.len() - end) + insert.len());
+        Self::check_len((self.url.len() - end - port_len) + insert.len());
+
+        if elide_port {
+            self.set_port(None);
+        }
 
-        // The host, port, path, query, & fragment follow the scheme & are unchanged, so their lengths are saved to
-        // rebuild the offsets that the splice shifts.
-        let host_len: u32 = self.host_end - self.scheme_len - 3;
+        // The user info, host, port, path, query, & fragment follow the scheme & are unchanged, so their lengths are
+        // saved to rebuild the offsets that the splice shifts.
+        let user_info_len: u32 = self.host_start - self.scheme_len - 3;
+        let host_len: u32 = self.host_end - self.host_start;
         let port_len: u32 = self.port_end - self.host_end;
         let path_len: u32 = self.path_end - self.port_end;
         let query_len: u32 = self.query_end - self.path_end;
@@ -44,7 +60,8 @@ impl WebUrl {
         self.url.replace_range(..end, insert);
 
         self.scheme_len = insert.len() as u32;
-        self.host_end = self.scheme_len + 3 + host_len;
+        self.host_start = self.scheme_len + 3 + user_info_len;
+        self.host_end = self.host_start + host_len;
         self.port_end = self.host_end + port_len;
         self.path_end = self.port_end + path_len;
         self.query_end = self.path_end + query_len;
@@ -90,9 +107,13 @@ mod tests {
             ("https://host/p?q#f", "s", "s://host/p?q#f"),
             ("https://host:8080/p", "http", "http://host:8080/p"),
             ("http://[::1]/p?q#f", "https", "https://[::1]/p?q#f"),
+            // The port is removed when it becomes the default port.
+            ("http://host:443/p?q#f", "https", "https://host/p?q#f"),
+            ("https://u@host:80/p", "ws", "ws://u@host/p"),
+            ("https://host:80/p", "s", "s://host:80/p"),
         ];
         for (input, scheme, expected) in test_cases {
-            let mut url: WebUrl = WebUrl::from_str(input)?;
+            let mut url: WebUrl = WebUrl::parse_with_user_info(input)?;
             url.set_scheme(Scheme::try_from(*scheme)?);
             assert_eq!(url.as_str(), *expected, "input={}", input);
             assert_eq!(url.scheme().as_str(), *scheme, "input={}", input);
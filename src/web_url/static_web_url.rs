@@ -0,0 +1,24 @@
+use crate::WebUrlRef;
+
+/// A web-based URL literal.
+///
+/// This is a `WebUrlRef` that borrows a `'static` URL string. It is const-constructible with `StaticWebUrl::new` & is
+/// usually created with the [`web_url!`](crate::web_url!) macro, which checks the URL at compile time. Use `to_owned`
+/// to get a `WebUrl`.
+pub type StaticWebUrl = WebUrlRef<'static>;
+
+/// Creates a [`StaticWebUrl`] from a URL literal, checked at compile time.
+///
+/// The URL must be valid & already normalized with `Normalizer::DEFAULT`, otherwise the build fails with the parse
+/// error message.
+#[macro_export]
+macro_rules! web_url {
+    ($url:expr $(,)?) => {
+        const {
+            match $crate::StaticWebUrl::new($url) {
+                ::core::result::Result::Ok(url) => url,
+                ::core::result::Result::Err(error) => ::core::panic!("{}", error.message()),
+            }
+        }
+    };
+}
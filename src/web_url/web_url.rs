@@ -1,3 +1,4 @@
+use crate::Normalizer;
 use crate::parse::{Parts, PrePath, parse_parts};
 use address::IPAddress;
 
@@ -8,12 +9,14 @@ use address::IPAddress;
 /// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3).
 ///
 /// - The `port`, `query`, & `fragment` are all optional.
+/// - The `user_info` is optional & only accepted when opted into, as in `scheme://user_info@host:port/path`.
 /// - The `path` will never be empty & will always start with a '/'.
 #[must_use]
 #[derive(Clone)]
 pub struct WebUrl {
     pub(in crate::web_url) url: String,
     pub(in crate::web_url) scheme_len: u32,
+    pub(in crate::web_url) host_start: u32,
     pub(in crate::web_url) host_end: u32,
     pub(in crate::web_url) ip: Option<IPAddress>,
     pub(in crate::web_url) port_end: u32,
@@ -45,15 +48,53 @@ impl WebUrl {
 impl WebUrl {
     //! Construction
 
-    /// Creates a new web-based URL.
+    /// Creates a new web-based URL with no user info.
+    ///
+    /// The host starts just past the "://", so this is `new_unchecked_with_host_start` with a `host_start` of
+    /// `scheme_len + 3`.
+    ///
+    /// # Safety
+    /// The `url` must have no user info & must meet the `new_unchecked_with_host_start` contract.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn new_unchecked<S>(
+        url: S,
+        scheme_len: u32,
+        host_end: u32,
+        ip: Option<IPAddress>,
+        port_end: u32,
+        port: Option<u16>,
+        path_end: u32,
+        query_end: u32,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        unsafe {
+            Self::new_unchecked_with_host_start(
+                url,
+                scheme_len,
+                scheme_len + 3,
+                host_end,
+                ip,
+                port_end,
+                port,
+                path_end,
+                query_end,
+            )
+        }
+    }
+
+    /// Creates a new web-based URL that may have user info.
     ///
     /// # Safety
     /// The `url` must be a normalized web-based URL & every other parameter must describe it:
-    /// - The `url` parses & is already normalized. The scheme & host are lowercase, an IP address host is in its
-    ///   canonical form, the path is present, starts with a '/', & has no dot-segments, an empty port is absent along
-    ///   with its ':', & the port has no leading zeros.
+    /// - The `url` parses with its user info allowed & is already normalized by [`Normalizer::MINIMAL`]. The scheme &
+    ///   host are lowercase, an IP address host is in its canonical form, the path is present & starts with a '/', an
+    ///   empty port is absent along with its ':', & the port has no leading zeros. The optional normalization steps are
+    ///   not required, so the path may have dot-segments.
     /// - The offsets are non-decreasing & the last is within the `url`:
-    ///   `scheme_len <= host_end <= port_end <= path_end <= query_end <= url.len()`.
+    ///   `scheme_len + 3 <= host_start <= host_end <= port_end <= path_end <= query_end <= url.len()`.
+    /// - The `host_start` is just past the "://" when there is no user info & just past the '@' when there is.
     /// - The `ip` is the parsed host when the host is an IP address & `None` when it is a domain.
     /// - The `port` is the parsed port & matches the `[host_end..port_end]` text.
     ///
@@ -65,9 +106,10 @@ impl WebUrl {
     /// the check is absent from release builds. Use `FromStr` or `TryFrom<String>` to build a URL that is validated in
     /// release builds too.
     #[allow(clippy::too_many_arguments)]
-    pub unsafe fn new_unchecked<S>(
+    pub unsafe fn new_unchecked_with_host_start<S>(
         url: S,
         scheme_len: u32,
+        host_start: u32,
         host_end: u32,
         ip: Option<IPAddress>,
         port_end: u32,
@@ -81,6 +123,7 @@ impl WebUrl {
         let url: Self = Self {
             url: url.into(),
             scheme_len,
+            host_start,
             host_end,
             ip,
             port_end,
@@ -91,11 +134,12 @@ impl WebUrl {
 
         debug_assert!(
             url.is_consistent(),
-            "web-url: WebUrl::new_unchecked was called with a URL that is not normalized or with \
-             offsets that do not describe it. The URL was {:?} with scheme_len={} host_end={} \
+            "web-url: WebUrl::new_unchecked_with_host_start was called with a URL that is not normalized or with \
+             offsets that do not describe it. The URL was {:?} with scheme_len={} host_start={} host_end={} \
              ip={:?} port_end={} port={:?} path_end={} query_end={}.",
             url.url,
             scheme_len,
+            host_start,
             host_end,
             ip,
             port_end,
@@ -113,7 +157,7 @@ impl WebUrl {
 
     /// Checks that the URL string is normalized & that every offset describes it.
     ///
-    /// This is the `new_unchecked` contract. It re-parses the URL, so it is only used in
+    /// This is the `new_unchecked_with_host_start` contract. It re-parses the URL, so it is only used in
     /// `debug_assert`s; parsing is exactly the work `new_unchecked` exists to skip.
     pub(in crate::web_url) fn is_consistent(&self) -> bool {
         // The offsets are checked before anything is sliced so that a bad offset returns false rather than panicking
@@ -122,11 +166,13 @@ impl WebUrl {
             return false;
         }
         let scheme_len: usize = self.scheme_len as usize;
+        let host_start: usize = self.host_start as usize;
         let host_end: usize = self.host_end as usize;
         let port_end: usize = self.port_end as usize;
         let path_end: usize = self.path_end as usize;
         let query_end: usize = self.query_end as usize;
-        if scheme_len > host_end
+        if scheme_len + 3 > host_start
+            || host_start > host_end
             || host_end > port_end
             || port_end > path_end
             || path_end > query_end
@@ -135,28 +181,31 @@ impl WebUrl {
             return false;
         }
 
-        // The parser is the oracle for the URL string. A normalized URL must parse, must need no further normalization,
-        // & must already be lowercase through the host.
-        let parts: Parts = match parse_parts(self.url.as_str()) {
+        // The parser is the oracle for the URL string. A normalized URL must parse, must need no further structural
+        // normalization, & must already have a lowercase scheme & host. The optional steps are skipped since a URL may
+        // be parsed with a normalizer that keeps what they remove. The user info is allowed since it is opted into when
+        // parsing.
+        let parts: Parts = match parse_parts(self.url.as_str(), Normalizer::MINIMAL.with_user_info(true)) {
             Ok(parts) => parts,
             Err(_) => return false,
         };
         if !parts.is_normalized() {
             return false;
         }
-        let pre_path: &PrePath = &parts.pre_path;
-        if self.url[..pre_path.host_end()].bytes().any(|c| c.is_ascii_uppercase()) {
+        if !parts.is_case_normalized(self.url.as_str()) {
             return false;
         }
+        let pre_path: &PrePath = &parts.pre_path;
 
         // Every offset must match what the parser found.
         scheme_len == pre_path.scheme_len
+            && host_start == pre_path.host_start()
             && host_end == pre_path.canonical_host_end()
             && self.ip == pre_path.ip
             && port_end == host_end + pre_path.canonical_port_len()
             && self.port == pre_path.port
             && path_end == port_end + parts.path_plus.canonical_path_len
-            && query_end == path_end + parts.path_plus.query_len
+            && query_end == path_end + parts.path_plus.canonical_query_len
     }
 }
 
@@ -215,4 +264,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn construction() -> Result<(), Box<dyn Error>> {
+        let url: WebUrl =
+            unsafe { WebUrl::new_unchecked("https://example.com:8080/p?q#f", 5, 19, None, 24, Some(8080), 26, 28) };
+        assert_eq!(url, WebUrl::from_str("https://example.com:8080/p?q#f")?);
+
+        let url: WebUrl = unsafe {
+            WebUrl::new_unchecked_with_host_start("https://user@example.com/", 5, 13, 24, None, 24, None, 25, 25)
+        };
+        assert_eq!(url, WebUrl::parse_with_user_info("https://user@example.com/")?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,417 @@
+use crate::parse::{Offsets, parse_const};
+use crate::{Error, Fragment, Normalizer, Origin, Path, Query, Scheme, TupleOrigin, UserInfo, WebUrl};
+use address::{DomainRef, HostRef, IPAddress};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A borrowed web-based URL.
+///
+/// This is a `WebUrl` that borrows its URL string rather than owning it, so parsing it never allocates. The components
+/// borrow from the URL string, so they outlive the `WebUrlRef` itself.
+///
+/// A URL can only be borrowed when it is already normalized, since normalizing it would need a new string. Parsing a
+/// URL that needs any rewrite fails with `NotNormalized`, including a URL that only needs its letter case normalized.
+/// Use `to_owned` to get a `WebUrl` to mutate.
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct WebUrlRef<'a> {
+    url: &'a str,
+    scheme_len: u32,
+    host_start: u32,
+    host_end: u32,
+    ip: Option<IPAddress>,
+    port_end: u32,
+    port: Option<u16>,
+    path_end: u32,
+    query_end: u32,
+}
+
+impl<'a> WebUrlRef<'a> {
+    //! Construction
+
+    /// Creates a new borrowed web-based URL.
+    ///
+    /// # Safety
+    /// The `url` & the offsets must meet the `WebUrl::new_unchecked_with_host_start` contract.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn new_unchecked(
+        url: &'a str,
+        scheme_len: u32,
+        host_start: u32,
+        host_end: u32,
+        ip: Option<IPAddress>,
+        port_end: u32,
+        port: Option<u16>,
+        path_end: u32,
+        query_end: u32,
+    ) -> Self {
+        let url: Self = Self {
+            url,
+            scheme_len,
+            host_start,
+            host_end,
+            ip,
+            port_end,
+            port,
+            path_end,
+            query_end,
+        };
+
+        // The owned URL checks the same contract, so the check is delegated rather than repeated.
+        debug_assert!(url.to_owned().is_consistent());
+
+        url
+    }
+
+    /// Creates a new borrowed web-based URL from the parsed `offsets`.
+    ///
+    /// # Safety
+    /// The `offsets` must have been parsed from the `url`.
+    pub(crate) const unsafe fn from_offsets(url: &'a str, offsets: Offsets) -> Self {
+        Self {
+            url,
+            scheme_len: offsets.scheme_len,
+            host_start: offsets.host_start,
+            host_end: offsets.host_end,
+            ip: offsets.ip,
+            port_end: offsets.port_end,
+            port: offsets.port,
+            path_end: offsets.path_end,
+            query_end: offsets.query_end,
+        }
+    }
+
+    /// Parses the web-based URL `url` & borrows it.
+    ///
+    /// The `url` must already be normalized with [`Normalizer::DEFAULT`](crate::Normalizer::DEFAULT). This is the
+    /// same as [`Normalizer::parse_ref`](crate::Normalizer::parse_ref) with the default profile, except that it is
+    /// const so a URL literal can be checked at compile time. (see [`web_url!`](crate::web_url!))
+    ///
+    /// The IDNA processing is not const, so a Unicode host is reported as an [`Error::InvalidHost`] rather than as
+    /// [`Error::NotNormalized`], & an `xn--` label is accepted without being decoded. Use `TryFrom` to reject an
+    /// invalid A-label.
+    pub const fn new(url: &'a str) -> Result<Self, Error> {
+        match parse_const(url) {
+            Ok(offsets) => Ok(unsafe { Self::from_offsets(url, offsets) }),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for WebUrlRef<'a> {
+    type Error = Error;
+
+    fn try_from(url: &'a str) -> Result<Self, Self::Error> {
+        Normalizer::DEFAULT.parse_ref(url)
+    }
+}
+
+impl<'a> WebUrlRef<'a> {
+    //! Properties
+
+    /// Gets the URL string.
+    #[must_use]
+    pub const fn as_str(self) -> &'a str {
+        self.url
+    }
+
+    /// Gets the scheme.
+    pub fn scheme(self) -> Scheme<'a> {
+        unsafe { Scheme::new_unchecked(&self.url[..self.scheme_len as usize]) }
+    }
+
+    /// Gets the optional user info.
+    #[must_use]
+    pub fn user_info(self) -> Option<UserInfo<'a>> {
+        let start: usize = (self.scheme_len + 3) as usize;
+        let end: usize = self.host_start as usize;
+        if start == end {
+            None
+        } else {
+            // The user info is followed by the '@', which is excluded.
+            Some(unsafe { UserInfo::new_unchecked(&self.url[start..(end - 1)]) })
+        }
+    }
+
+    /// Gets the host reference.
+    pub fn host(self) -> HostRef<'a> {
+        if let Some(ip) = self.ip {
+            HostRef::Address(ip)
+        } else {
+            let host: &str = &self.url[self.host_start as usize..self.host_end as usize];
+            HostRef::Name(unsafe { DomainRef::new_unchecked(host) })
+        }
+    }
+
+    /// Gets the optional port.
+    #[must_use]
+    pub const fn port(self) -> Option<u16> {
+        self.port
+    }
+
+    /// Gets the effective port. (see [`WebUrl::effective_port`])
+    #[must_use]
+    pub fn effective_port(self) -> Option<u16> {
+        self.port.or_else(|| self.scheme().default_port())
+    }
+
+    /// Gets the origin. (see [`WebUrl::origin`])
+    #[must_use]
+    pub fn origin(self) -> Origin {
+        match TupleOrigin::new(self.scheme(), self.host(), self.port) {
+            Some(tuple) => Origin::Tuple(tuple),
+            None => Origin::Opaque,
+        }
+    }
+
+    /// Gets the path.
+    pub fn path(self) -> Path<'a> {
+        unsafe { Path::new_unchecked(&self.url[self.port_end as usize..self.path_end as usize]) }
+    }
+
+    /// Gets the optional query.
+    #[must_use]
+    pub fn query(self) -> Option<Query<'a>> {
+        let query: &str = &self.url[self.path_end as usize..self.query_end as usize];
+        if query.is_empty() {
+            None
+        } else {
+            Some(unsafe { Query::new_unchecked(query) })
+        }
+    }
+
+    /// Gets the optional fragment.
+    #[must_use]
+    pub fn fragment(self) -> Option<Fragment<'a>> {
+        let fragment: &str = &self.url[self.query_end as usize..];
+        if fragment.is_empty() {
+            None
+        } else {
+            Some(unsafe { Fragment::new_unchecked(fragment) })
+        }
+    }
+}
+
+impl<'a> WebUrlRef<'a> {
+    //! Conversion
+
+    /// Converts the borrowed URL to an owned URL.
+    ///
+    /// The URL string is copied as it is, since it is already normalized.
+    pub fn to_owned(self) -> WebUrl {
+        unsafe {
+            WebUrl::new_unchecked_with_host_start(
+                self.url,
+                self.scheme_len,
+                self.host_start,
+                self.host_end,
+                self.ip,
+                self.port_end,
+                self.port,
+                self.path_end,
+                self.query_end,
+            )
+        }
+    }
+}
+
+impl<'a> From<WebUrlRef<'a>> for WebUrl {
+    fn from(url: WebUrlRef<'a>) -> Self {
+        url.to_owned()
+    }
+}
+
+impl<'a> Ord for WebUrlRef<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.url.cmp(other.url)
+    }
+}
+
+impl<'a> PartialOrd for WebUrlRef<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Eq for WebUrlRef<'a> {}
+
+impl<'a> PartialEq for WebUrlRef<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+    }
+}
+
+impl<'a> Hash for WebUrlRef<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.url.hash(state)
+    }
+}
+
+impl<'a> PartialEq<WebUrl> for WebUrlRef<'a> {
+    fn eq(&self, other: &WebUrl) -> bool {
+        self.url == other.as_str()
+    }
+}
+
+impl<'a> PartialEq<WebUrlRef<'a>> for WebUrl {
+    fn eq(&self, other: &WebUrlRef<'a>) -> bool {
+        self.as_str() == other.url
+    }
+}
+
+impl<'a> PartialEq<str> for WebUrlRef<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.url == other
+    }
+}
+
+impl<'a> PartialEq<WebUrlRef<'a>> for str {
+    fn eq(&self, other: &WebUrlRef<'a>) -> bool {
+        self == other.url
+    }
+}
+
+impl<'a> PartialEq<&str> for WebUrlRef<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.url == *other
+    }
+}
+
+impl<'a> PartialEq<WebUrlRef<'a>> for &str {
+    fn eq(&self, other: &WebUrlRef<'a>) -> bool {
+        *self == other.url
+    }
+}
+
+impl<'a> AsRef<str> for WebUrlRef<'a> {
+    fn as_ref(&self) -> &str {
+        self.url
+    }
+}
+
+impl<'a> Debug for WebUrlRef<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for WebUrlRef<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error::{InvalidHost, NotNormalized, UserInfoNotSupported};
+    use crate::{Error, Normalizer, StaticWebUrl, WebUrl, WebUrlRef};
+    use address::{HostRef, IPv6Address};
+    use std::str::FromStr;
+
+    #[test]
+    fn try_from() {
+        let test_cases: &[(&str, Result<(), Error>)] = &[
+            ("http://host/", Ok(())),
+            ("http://host:8080/p?q#f", Ok(())),
+            ("http://[::1]/p", Ok(())),
+            ("http://host/%2F?%3D#%3F", Ok(())),
+            // Every rewrite needs a new string, even the ones done in place.
+            ("http://host", Err(NotNormalized)),
+            ("http://host:80/", Err(NotNormalized)),
+            ("http://host:/", Err(NotNormalized)),
+            ("http://[0::1]/", Err(NotNormalized)),
+            ("http://host/a/../b", Err(NotNormalized)),
+            ("http://host/%7e", Err(NotNormalized)),
+            ("http://host/%2f", Err(NotNormalized)),
+            ("HTTP://host/", Err(NotNormalized)),
+            ("http://HOST/", Err(NotNormalized)),
+            #[cfg(feature = "idna")]
+            ("http://b\u{fc}cher.example/", Err(NotNormalized)),
+            ("http://xn--bcher-kva.example/", Ok(())),
+            // An invalid URL reports why it is invalid.
+            ("http://ho st/", Err(InvalidHost)),
+            ("http://user@host/", Err(UserInfoNotSupported)),
+        ];
+        for (input, expected) in test_cases {
+            let result: Result<WebUrlRef, Error> = WebUrlRef::try_from(*input);
+            assert_eq!(result.map(|url| assert_eq!(url, *input)), *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn new_const() {
+        const URL: StaticWebUrl = crate::web_url!("https://[::1]:8080/a/b?x=1#frag");
+        assert_eq!(URL, "https://[::1]:8080/a/b?x=1#frag");
+        assert_eq!(URL.port(), Some(8080));
+        assert_eq!(URL.path().as_str(), "/a/b");
+        assert_eq!(URL.to_owned(), WebUrl::from_str(URL.as_str()).unwrap());
+
+        const INVALID: Result<StaticWebUrl, Error> = StaticWebUrl::new("https://example.com/a/../b");
+        assert_eq!(INVALID, Err(NotNormalized));
+
+        // A Unicode host needs the IDNA processing, which is not const.
+        const UNICODE: Result<StaticWebUrl, Error> = StaticWebUrl::new("https://b\u{fc}cher.example/");
+        assert_eq!(UNICODE, Err(InvalidHost));
+    }
+
+    #[test]
+    fn parse_ref_normalizer() -> Result<(), Box<dyn std::error::Error>> {
+        // The URL must be normalized with the profile it is parsed with.
+        let input: &str = "http://u@host:80/a/../%7e?";
+        assert_eq!(
+            Normalizer::DEFAULT.with_user_info(true).parse_ref(input),
+            Err(NotNormalized)
+        );
+
+        let url: WebUrlRef = Normalizer::MINIMAL.with_user_info(true).parse_ref(input)?;
+        assert_eq!(url.as_str(), input);
+        assert_eq!(url.user_info().unwrap().as_str(), "u");
+        assert_eq!(url.port(), Some(80));
+        assert_eq!(url.path().as_str(), "/a/../%7e");
+
+        Ok(())
+    }
+
+    #[test]
+    fn accessors() -> Result<(), Box<dyn std::error::Error>> {
+        let input: String = String::from("https://[::1]:8080/a/b?x=1#frag");
+        let url: WebUrlRef = WebUrlRef::try_from(input.as_str())?;
+        let owned: WebUrl = WebUrl::from_str(input.as_str())?;
+
+        assert_eq!(url.scheme(), owned.scheme());
+        assert_eq!(url.user_info(), None);
+        assert_eq!(url.host(), HostRef::Address(IPv6Address::LOCALHOST.to_ip()));
+        assert_eq!(url.port(), Some(8080));
+        assert_eq!(url.effective_port(), Some(8080));
+        assert_eq!(url.path(), owned.path());
+        assert_eq!(url.query(), owned.query());
+        assert_eq!(url.fragment(), owned.fragment());
+        assert_eq!(url.to_string(), input);
+
+        // The components borrow from the input, not from the `WebUrlRef`.
+        let path: &str = WebUrlRef::try_from(input.as_str())?.path().as_str();
+        assert_eq!(path, "/a/b");
+
+        let url: WebUrlRef = WebUrlRef::try_from("http://example.com/")?;
+        assert_eq!(url.host().to_string(), "example.com");
+        assert_eq!(url.port(), None);
+        assert_eq!(url.effective_port(), Some(80));
+        assert_eq!(url.query(), None);
+        assert_eq!(url.fragment(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_owned() -> Result<(), Box<dyn std::error::Error>> {
+        let url: WebUrlRef = WebUrlRef::try_from("https://example.com:8080/p?q#f")?;
+        let owned: WebUrl = url.to_owned();
+        assert_eq!(owned, WebUrl::from_str("https://example.com:8080/p?q#f")?);
+        assert_eq!(owned, url);
+        assert_eq!(owned.port(), Some(8080));
+        assert_eq!(WebUrl::from(url), owned);
+
+        Ok(())
+    }
+}
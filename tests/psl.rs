@@ -0,0 +1,57 @@
+//! The Public Suffix List conformance test.
+//!
+//! The `public_suffix_tests.txt` file is a vendored copy of the test vectors of the Public Suffix List project, which
+//! are in the public domain. Each line is a domain & its expected registrable domain, or `null` when it has none. The
+//! Unicode domains need the `idna` feature.
+#![cfg(all(feature = "idna", feature = "psl"))]
+
+use std::str::{FromStr, SplitWhitespace};
+use web_url::WebUrl;
+
+/// The vendored test vectors.
+const TEST_DATA: &str = include_str!("public_suffix_tests.txt");
+
+#[test]
+fn public_suffix_tests() {
+    let mut tested: usize = 0;
+    let mut failures: Vec<String> = Vec::new();
+    for line in TEST_DATA.lines() {
+        let mut fields: SplitWhitespace = line.split_whitespace();
+        let (Some(input), Some(expected)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if input.starts_with("//") || input == "null" {
+            continue;
+        }
+        tested += 1;
+
+        // A domain that is not a valid host, like one with a leading dot, has no registrable domain.
+        let result: Option<String> = host_url(input)
+            .ok()
+            .and_then(|url| url.registrable_domain().map(|domain| domain.name().to_string()));
+        let expected: Option<String> = match expected {
+            "null" => None,
+            expected => Some(
+                host_url(expected)
+                    .expect("the expected domain is valid")
+                    .host()
+                    .to_string(),
+            ),
+        };
+        if result != expected {
+            failures.push(format!("input={:?} result={:?} expected={:?}", input, result, expected));
+        }
+    }
+    assert!(tested > 60, "only {} cases were tested", tested);
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// Parses the URL with the `host`.
+fn host_url(host: &str) -> Result<WebUrl, web_url::Error> {
+    WebUrl::from_str(&format!("http://{}/", host))
+}